@@ -0,0 +1,41 @@
+//! Runtime API definition for the proposals pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use proposals::{
+    MilestoneKey, MilestoneSubmission, Project, ProjectKey, Round, RoundKey, VestingSchedule,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait ProposalsApi<AccountId, Balance, BlockNumber> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Get a single project.
+        fn get_project(project_key: ProjectKey) -> Option<Project<AccountId, Balance, BlockNumber>>;
+
+        /// Get up to `limit` projects, starting at `start_key`.
+        fn get_projects(start_key: ProjectKey, limit: u32) -> Vec<(ProjectKey, Project<AccountId, Balance, BlockNumber>)>;
+
+        /// Get the contribution `who` made to a project.
+        fn get_contribution(project_key: ProjectKey, who: AccountId) -> Option<Balance>;
+
+        /// Get the milestone votes of `who` on a project as (milestone, round, approved).
+        fn get_user_milestone_votes(project_key: ProjectKey, who: AccountId) -> Vec<(MilestoneKey, RoundKey, bool)>;
+
+        /// Get the funds the initiator of a project can withdraw right now.
+        fn get_withdrawable_funds(project_key: ProjectKey) -> Option<Balance>;
+
+        /// Get every submission of a milestone and its outcome, the latest submission comes last.
+        fn get_milestone_submissions(project_key: ProjectKey, milestone_key: MilestoneKey) -> Vec<MilestoneSubmission<Balance, BlockNumber>>;
+
+        /// Get the withdrawals of a project which are still vesting.
+        fn get_vesting_schedules(project_key: ProjectKey) -> Vec<VestingSchedule<Balance, BlockNumber>>;
+
+        /// Get the round a project is currently part of.
+        fn get_active_round(project_key: ProjectKey) -> Option<(RoundKey, Round<BlockNumber>)>;
+    }
+}
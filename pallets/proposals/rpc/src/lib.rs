@@ -0,0 +1,215 @@
+//! JSON-RPC interface for the proposals pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use proposals::{
+    MilestoneKey, MilestoneSubmission, Project, ProjectKey, Round, RoundKey, VestingSchedule,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use proposals_rpc_runtime_api::ProposalsApi as ProposalsRuntimeApi;
+
+/// Projects returned by a single `proposals_getProjects` call are capped at this number.
+const MAX_PROJECTS_PER_PAGE: u32 = 100;
+
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait ProposalsApi<BlockHash, AccountId, Balance, BlockNumber> {
+    #[method(name = "proposals_getProject")]
+    fn get_project(
+        &self,
+        project_key: ProjectKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Project<AccountId, Balance, BlockNumber>>>;
+
+    #[method(name = "proposals_getProjects")]
+    fn get_projects(
+        &self,
+        start_key: ProjectKey,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(ProjectKey, Project<AccountId, Balance, BlockNumber>)>>;
+
+    #[method(name = "proposals_getContribution")]
+    fn get_contribution(
+        &self,
+        project_key: ProjectKey,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Balance>>;
+
+    #[method(name = "proposals_getUserMilestoneVotes")]
+    fn get_user_milestone_votes(
+        &self,
+        project_key: ProjectKey,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(MilestoneKey, RoundKey, bool)>>;
+
+    #[method(name = "proposals_getWithdrawableFunds")]
+    fn get_withdrawable_funds(
+        &self,
+        project_key: ProjectKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Balance>>;
+
+    #[method(name = "proposals_getMilestoneSubmissions")]
+    fn get_milestone_submissions(
+        &self,
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<MilestoneSubmission<Balance, BlockNumber>>>;
+
+    #[method(name = "proposals_getVestingSchedules")]
+    fn get_vesting_schedules(
+        &self,
+        project_key: ProjectKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VestingSchedule<Balance, BlockNumber>>>;
+
+    #[method(name = "proposals_getActiveRound")]
+    fn get_active_round(
+        &self,
+        project_key: ProjectKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<(RoundKey, Round<BlockNumber>)>>;
+}
+
+/// Provides RPC methods to query the proposals pallet.
+pub struct Proposals<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Proposals<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Runtime error",
+        Some(format!("{:?}", err)),
+    ))
+    .into()
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+    ProposalsApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
+    for Proposals<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ProposalsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec + Clone + Send + Sync + 'static + Serialize + DeserializeOwned,
+    Balance: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+    BlockNumber: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+{
+    fn get_project(
+        &self,
+        project_key: ProjectKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Project<AccountId, Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_project(&at, project_key)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_projects(
+        &self,
+        start_key: ProjectKey,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(ProjectKey, Project<AccountId, Balance, BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_projects(&at, start_key, limit.min(MAX_PROJECTS_PER_PAGE))
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_contribution(
+        &self,
+        project_key: ProjectKey,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_contribution(&at, project_key, who)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_user_milestone_votes(
+        &self,
+        project_key: ProjectKey,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(MilestoneKey, RoundKey, bool)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_user_milestone_votes(&at, project_key, who)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_withdrawable_funds(
+        &self,
+        project_key: ProjectKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_withdrawable_funds(&at, project_key)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_milestone_submissions(
+        &self,
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<MilestoneSubmission<Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_milestone_submissions(&at, project_key, milestone_key)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_vesting_schedules(
+        &self,
+        project_key: ProjectKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VestingSchedule<Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_vesting_schedules(&at, project_key)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_active_round(
+        &self,
+        project_key: ProjectKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<(RoundKey, Round<BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.get_active_round(&at, project_key)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
@@ -1,16 +1,14 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, BenchmarkError};
 use frame_system::{EventRecord, RawOrigin};
 
 use crate::Pallet as Proposals;
 use common_types::CurrencyId;
-use frame_support::{
-    traits::{Currency},
-};
+use orml_traits::MultiCurrency;
 use sp_std::str;
 use sp_std::vec::Vec;
-use sp_runtime::traits::UniqueSaturatedFrom;
+use sp_runtime::{traits::UniqueSaturatedFrom, DispatchError};
 
 
 const CONTRIBUTION: u32 = 100;
@@ -23,181 +21,263 @@ benchmarks! {
 
     create_project{
         //let a in 1 .. 100;
-        let caller: T::AccountId = whitelisted_caller();
-        let project_name: Vec<u8> = str::from_utf8(b"Imbue's Awesome Initiative").unwrap().as_bytes().to_vec();
-        let project_logo: Vec<u8> = str::from_utf8(b"Imbue Logo").unwrap().as_bytes().to_vec();
-        let project_description: Vec<u8> = str::from_utf8(b"This project is aimed at promoting Decentralised Data and Transparent Crowdfunding.").unwrap().as_bytes().to_vec();
-        let website: Vec<u8> = str::from_utf8(b"https://imbue.network").unwrap().as_bytes().to_vec();
-        let milestones: Vec<ProposedMilestone> = vec![ProposedMilestone {
-            name: Vec::new(),
-            percentage_to_unlock: 100,
-        }];
+        let caller: T::AccountId = create_funded_user::<T>("caller", 1, 1000);
+        let project_name: BoundedStringField = str::from_utf8(b"Imbue's Awesome Initiative").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let project_logo: BoundedStringField = str::from_utf8(b"Imbue Logo").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let project_description: BoundedDescriptionField = str::from_utf8(b"This project is aimed at promoting Decentralised Data and Transparent Crowdfunding.").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let website: BoundedDescriptionField = str::from_utf8(b"https://imbue.network").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let milestones: BoundedProposedMilestones = vec![proposed_milestone(100, None)].try_into().unwrap();
 
         let required_funds: BalanceOf<T> = 100u32.into();
         let currency_id = CurrencyId::Native;
 
     }: _(RawOrigin::Signed(caller.clone()), project_name.clone(), project_logo, project_description, website, milestones, required_funds, currency_id)
     verify {
-        assert_last_event::<T>(Event::ProjectCreated(caller,project_name.clone(),0, required_funds, currency_id).into());
+        assert_last_event::<T>(Event::ProjectCreated(caller,project_name.to_vec(),0, required_funds, currency_id).into());
+    }
+
+    update_project {
+        let bob: T::AccountId = create_project_common::<T>(CONTRIBUTION.into(), None);
+        let project_name: BoundedStringField = str::from_utf8(b"Imbue's Updated Initiative").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let project_logo: BoundedStringField = str::from_utf8(b"Imbue Logo").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let project_description: BoundedDescriptionField = str::from_utf8(b"This project is aimed at promoting Decentralised Data and Transparent Crowdfunding.").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let website: BoundedDescriptionField = str::from_utf8(b"https://imbue.network").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let milestones: BoundedProposedMilestones = vec![proposed_milestone(50, None), proposed_milestone(50, None)].try_into().unwrap();
+        let required_funds: BalanceOf<T> = 200u32.into();
+
+    }: _(RawOrigin::Signed(bob.clone()), 0, project_name, project_logo, project_description, website, milestones, required_funds)
+    verify {
+        assert_last_event::<T>(Event::ProjectUpdated(bob, 0, required_funds).into());
+    }
+
+    // Every project management call reads the project and writes a single setting,
+    // the funding caps are the largest of them.
+    manage_project {
+        let bob: T::AccountId = create_project_common::<T>(CONTRIBUTION.into(), None);
+        let funding_caps = FundingCaps {
+            soft_cap: Some(Perbill::from_percent(50)),
+            hard_cap: Some(contribution_amount::<T>()),
+            return_overfunding: true,
+        };
+
+    }: set_funding_caps(RawOrigin::Signed(bob), 0, funding_caps.clone())
+    verify {
+        assert_last_event::<T>(Event::FundingCapsUpdated(0, funding_caps).into());
+    }
+
+    accept_ownership_transfer {
+        // The team of the previous owner is removed
+        let t in 1 .. T::MaxTeamMembersPerProject::get();
+
+        let bob: T::AccountId = create_project_common::<T>(CONTRIBUTION.into(), None);
+        let charlie: T::AccountId = create_funded_user::<T>("charlie", 1, 1000);
+        for i in 0 .. t {
+            let member: T::AccountId = account("member", i, SEED);
+            let roles: BoundedProjectRoles = vec![ProjectRole::SubmitMilestones].try_into().unwrap();
+            Proposals::<T>::set_team_member(RawOrigin::Signed(bob.clone()).into(), 0, member, roles)?;
+        }
+        Proposals::<T>::set_beneficiary(RawOrigin::Signed(bob.clone()).into(), 0, Some(charlie.clone()))?;
+        Proposals::<T>::propose_ownership_transfer(RawOrigin::Signed(bob.clone()).into(), 0, charlie.clone())?;
+
+    }: _(RawOrigin::Signed(charlie.clone()), 0)
+    verify {
+        assert_eq!(Proposals::<T>::project_team_size(0), 0);
+        assert_last_event::<T>(Event::OwnershipTransferred(0, bob, charlie).into());
     }
 
     schedule_round {
-        create_project_common::<T>(CONTRIBUTION);
+        let s in 1 .. T::MaxProposalsPerRound::get();
+
+        MaxProposalCountPerRound::<T>::put(T::MaxProposalsPerRound::get());
+        for _ in 0 .. s {
+            create_project_common::<T>(CONTRIBUTION.into(), None);
+        }
+        let origin = T::RoundOrigin::successful_origin();
         let start_block: T::BlockNumber = 0u32.into();
         let end_block: T::BlockNumber = 10u32.into();
-        let project_keys: Vec<ProjectKey> = vec![0];
+        let project_keys: BoundedProjectKeys = (0 .. s).collect::<Vec<ProjectKey>>().try_into().unwrap();
 
-    }: _(RawOrigin::Root, start_block, end_block, project_keys.clone(), RoundType::ContributionRound)
+    }: _<T::Origin>(origin, start_block, end_block, project_keys.clone(), RoundType::ContributionRound)
     verify {
-        assert_last_event::<T>(Event::FundingRoundCreated(0, project_keys).into());
+        assert_last_event::<T>(Event::FundingRoundCreated(0, project_keys.to_vec()).into());
     }
 
     cancel_round {
-        
-        let caller: T::AccountId = whitelisted_caller();
-        //Setting the start block to be greater than 0 which is the current block. 
-        //This condition is checked to ensure the round being cancelled has not started yet.
-        //Benchmark seems to be starting at block 1, hence setting starting block to 2
+        let s in 1 .. T::MaxProposalsPerRound::get();
+
+        MaxProposalCountPerRound::<T>::put(T::MaxProposalsPerRound::get());
+        for _ in 0 .. s {
+            create_project_common::<T>(CONTRIBUTION.into(), None);
+        }
+        let origin = T::RoundOrigin::successful_origin();
+        //Setting the end block to be greater than the current block.
+        //This condition is checked to ensure the round being cancelled has not ended yet.
+        run_to_block::<T>(1u32.into());
         let start_block: T::BlockNumber = 2u32.into();
         let end_block: T::BlockNumber = 10u32.into();
-        let project_key: Vec<ProjectKey> = vec![0];
-        
-        //create project
-        create_project_common::<T>(CONTRIBUTION);
-        //schedule round
-        Proposals::<T>::schedule_round(RawOrigin::Root.into(), start_block, end_block, project_key, RoundType::ContributionRound)?;
+        let project_keys: BoundedProjectKeys = (0 .. s).collect::<Vec<ProjectKey>>().try_into().unwrap();
+        Proposals::<T>::schedule_round(T::RoundOrigin::successful_origin(), start_block, end_block, project_keys, RoundType::ContributionRound)?;
 
-    }: _(RawOrigin::Root, 0)
+    }: _<T::Origin>(origin, 0)
     verify {
-       //assert_last_event::<T>(Event::RoundCancelled(0).into());
+        assert_last_event::<T>(Event::RoundCancelled(0).into());
     }
 
-    contribute {
-        
-        //create a funded user for contribution
-        let alice: T::AccountId = create_funded_user::<T>("candidate", 1, 1000);
+    update_round_projects {
+        let s in 1 .. T::MaxProposalsPerRound::get() - 1;
 
-        
-        //Setting the start block to be greater than 0 which is the current block. 
-        //This condition is checked to ensure the round being cancelled has not started yet.
-        //Benchmark seems to be starting at block 1, hence setting starting block to 2
+        MaxProposalCountPerRound::<T>::put(T::MaxProposalsPerRound::get());
+        for _ in 0 ..= s {
+            create_project_common::<T>(CONTRIBUTION.into(), None);
+        }
+        let origin = T::RoundOrigin::successful_origin();
+        run_to_block::<T>(1u32.into());
         let start_block: T::BlockNumber = 2u32.into();
         let end_block: T::BlockNumber = 10u32.into();
-        let project_key: Vec<ProjectKey> = vec![0];
+        let project_keys: BoundedProjectKeys = vec![0].try_into().unwrap();
+        Proposals::<T>::schedule_round(T::RoundOrigin::successful_origin(), start_block, end_block, project_keys, RoundType::ContributionRound)?;
+        let projects_to_add: BoundedProjectKeys = (1 ..= s).collect::<Vec<ProjectKey>>().try_into().unwrap();
+        let projects_to_remove: BoundedProjectKeys = Vec::new().try_into().unwrap();
+
+    }: _<T::Origin>(origin, 0, projects_to_add, projects_to_remove)
+    verify {
+        assert_last_event::<T>(Event::RoundProjectsUpdated(0, (0 ..= s).collect()).into());
+    }
+
+    contribute {
+        //create a funded user for contribution
+        let alice: T::AccountId = create_funded_user::<T>("candidate", 1, 1000);
         let currency_id = CurrencyId::Native;
-        let contribution_amount: BalanceOf<T> = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000 as u128);
+        let contribution_amount: BalanceOf<T> = contribution_amount::<T>();
         let progress_block_number: <T as frame_system::Config>::BlockNumber = 3u32.into();
-        
+
         //create project
-        create_project_common::<T>(CONTRIBUTION);
+        create_project_common::<T>(CONTRIBUTION.into(), None);
         //schedule round
-        Proposals::<T>::schedule_round(RawOrigin::Root.into(), start_block, end_block, project_key, RoundType::ContributionRound)?;
+        schedule_contribution_round::<T>(0)?;
         //progress the blocks
         run_to_block::<T>(progress_block_number);
 
     }: _(RawOrigin::Signed(alice.clone()), 0, contribution_amount)
     verify {
-        //assert_last_event::<T>(Event::ContributeSucceeded(alice,0,contribution_amount,currency_id,progress_block_number).into());
+        assert_last_event::<T>(Event::ContributeSucceeded(alice,0,contribution_amount,currency_id,progress_block_number).into());
     }
 
-    approve {        
-        //create a funded user for contribution
+    withdraw_contribution {
         let alice: T::AccountId = create_funded_user::<T>("candidate", 1, 1000);
-
-        //Setting the start block to be greater than 0 which is the current block. 
-        //This condition is checked to ensure the round being cancelled has not started yet.
-        //Benchmark seems to be starting at block 1, hence setting starting block to 2
-        let start_block: T::BlockNumber = 2u32.into();
-        let end_block: T::BlockNumber = 10u32.into();
-        let project_key: Vec<ProjectKey> = vec![0];
         let currency_id = CurrencyId::Native;
-        let contribution_amount: BalanceOf<T> = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000 as u128);
-        let milestone_keys: Vec<MilestoneKey> = vec![0];
+        let contribution_amount: BalanceOf<T> = contribution_amount::<T>();
         let progress_block_number: <T as frame_system::Config>::BlockNumber = 3u32.into();
-        
-        //create project
-        create_project_common::<T>(CONTRIBUTION);
-        //schedule round
-        Proposals::<T>::schedule_round(RawOrigin::Root.into(), start_block, end_block, project_key, RoundType::ContributionRound)?;
-        //progress the blocks
+
+        create_project_common::<T>(CONTRIBUTION.into(), None);
+        schedule_contribution_round::<T>(0)?;
         run_to_block::<T>(progress_block_number);
-        //contribute
         Proposals::<T>::contribute(RawOrigin::Signed(alice.clone()).into(), 0, contribution_amount)?;
-        
+
+        // Withdrawing the whole contribution also removes the contributor
+    }: _(RawOrigin::Signed(alice.clone()), 0, contribution_amount)
+    verify {
+        assert_last_event::<T>(Event::ContributionWithdrawn(alice,0,contribution_amount,currency_id,progress_block_number).into());
+    }
+
+    fund_matching_pool {
+        let sponsor: T::AccountId = create_funded_user::<T>("sponsor", 1, 1000);
+        let currency_id = CurrencyId::Native;
+        let value: BalanceOf<T> = contribution_amount::<T>();
+
+        create_project_common::<T>(CONTRIBUTION.into(), None);
+        schedule_contribution_round::<T>(0)?;
+        run_to_block::<T>(3u32.into());
+
+    }: _(RawOrigin::Signed(sponsor.clone()), 0, currency_id, value)
+    verify {
+        assert_last_event::<T>(Event::MatchingPoolFunded(0, sponsor, value, currency_id).into());
+    }
+
+    approve {
+        // Reserved contributions are moved and the overfunding is returned to every contributor
+        let c in 1 .. T::MaxContributorsPerProject::get();
+
+        let bob: T::AccountId = create_project_common::<T>(CONTRIBUTION.into(), None);
+        let origin = T::AdminOrigin::successful_origin();
+        let milestone_keys: BoundedMilestoneKeys = vec![0].try_into().unwrap();
+        let funding_caps = FundingCaps {
+            soft_cap: None,
+            hard_cap: None,
+            return_overfunding: true,
+        };
+        Proposals::<T>::set_reserve_contributions(RawOrigin::Signed(bob.clone()).into(), 0, true)?;
+        Proposals::<T>::set_funding_caps(RawOrigin::Signed(bob).into(), 0, funding_caps)?;
+        schedule_contribution_round::<T>(0)?;
+        run_to_block::<T>(3u32.into());
+        contribute_to_project::<T>(0, c)?;
+
         //2nd argument - project key
-    }: _(RawOrigin::Root, 0, Some(milestone_keys))
+    }: _<T::Origin>(origin, 0, Some(milestone_keys))
     verify {
-       //assert_last_event::<T>(Event::ProjectApproved(1,0).into());
+        assert_last_event::<T>(Event::ProjectApproved(1, 0).into());
     }
 
-    submit_milestone { 
-        let alice: T::AccountId = create_funded_user::<T>("contributor", 1, 1000);
-        let bob: T::AccountId = create_funded_user::<T>("initiator", 1, 1000);
+    release_reserved_contributions {
+        let c in 1 .. T::MaxContributorsPerProject::get();
 
-        //Setting the start block to be greater than 0 which is the current block. 
-        //This condition is checked to ensure the round being cancelled has not started yet.
-        //Benchmark seems to be starting at block 1, hence setting starting block to 2
-        let start_block: T::BlockNumber = 2u32.into();
-        let end_block: T::BlockNumber = 10u32.into();
-        let project_key: Vec<ProjectKey> = vec![0];
-        let currency_id = CurrencyId::Native;
-        let contribution_amount: BalanceOf<T> = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000 as u128);
-        let milestone_keys: Vec<MilestoneKey> = vec![0];
-        let progress_block_number: <T as frame_system::Config>::BlockNumber = 3u32.into();
-        
-        
-        //create project
-        create_project_common::<T>(CONTRIBUTION);
-        //Proposals::<T>::create_project(RawOrigin::Signed(caller.clone()).into(), project_name.clone(), project_logo, project_description, website, milestones, required_funds, currency_id)?;
-        //schedule round
-        Proposals::<T>::schedule_round(RawOrigin::Root.into(), start_block, end_block, project_key, RoundType::ContributionRound)?;
-        //progress the blocks
-        run_to_block::<T>(progress_block_number);
-        //contribute
-        Proposals::<T>::contribute(RawOrigin::Signed(alice.clone()).into(), 0, contribution_amount)?;
-        //Approve
-        Proposals::<T>::approve(RawOrigin::Root.into(), 0, Some(milestone_keys))?;
+        let caller: T::AccountId = whitelisted_caller();
+        // The contributions can never reach the required funds
+        let required_funds: BalanceOf<T> = BalanceOf::<T>::unique_saturated_from(u128::MAX);
+        let bob: T::AccountId = create_project_common::<T>(required_funds, None);
+        Proposals::<T>::set_reserve_contributions(RawOrigin::Signed(bob).into(), 0, true)?;
+        schedule_contribution_round::<T>(0)?;
+        run_to_block::<T>(3u32.into());
+        contribute_to_project::<T>(0, c)?;
+        run_to_block::<T>(11u32.into());
+        let released_funds = Proposals::<T>::project_contributions_total(0);
+
+    }: _(RawOrigin::Signed(caller), 0)
+    verify {
+        assert_last_event::<T>(Event::ReservedContributionsReleased(0, released_funds).into());
+    }
+
+    submit_milestone {
+        // Every contribution is snapshotted for the vote
+        let c in 1 .. T::MaxContributorsPerProject::get();
+
+        let (bob, _) = fund_project::<T>(c)?;
 
         //project key - 2nd argument as u32 instead of vec
         //Milestone key - 3rd argument as u32
-    }: _(RawOrigin::Signed(bob.clone()), 0, 0)
+    }: _(RawOrigin::Signed(bob.clone()), 0, 0, milestone_evidence())
     verify {
-       //assert_last_event::<T>(Event::VotingRoundCreated(1).into());
+        assert_last_event::<T>(Event::VotingRoundCreated(1, vec![0]).into());
     }
 
-    vote_on_milestone { 
-        let alice: T::AccountId = create_funded_user::<T>("contributor", 1, 1000);
-        let bob: T::AccountId = create_funded_user::<T>("initiator", 1, 1000);
+    delegate_votes {
+        let alice: T::AccountId = create_funded_user::<T>("delegator", 0, 1000);
+        let previous_delegate: T::AccountId = account("previous_delegate", 0, SEED);
+        let delegate: T::AccountId = account("delegate", 0, SEED);
 
-        //Setting the start block to be greater than 0 which is the current block. 
-        //This condition is checked to ensure the round being cancelled has not started yet.
-        //Benchmark seems to be starting at block 1, hence setting starting block to 2
-        let start_block: T::BlockNumber = 2u32.into();
-        let end_block: T::BlockNumber = 10u32.into();
-        let project_key: Vec<ProjectKey> = vec![0];
-        let currency_id = CurrencyId::Native;
-        let contribution_amount: BalanceOf<T> = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000 as u128);
-        let milestone_keys: Vec<MilestoneKey> = vec![0];
-        let progress_block_number_contribute: <T as frame_system::Config>::BlockNumber = 3u32.into();
-        let progress_block_number_vote_on_milestone: <T as frame_system::Config>::BlockNumber = 11u32.into();
-        
-        
-        //create project
-        create_project_common::<T>(CONTRIBUTION);
-        //Proposals::<T>::create_project(RawOrigin::Signed(caller.clone()).into(), project_name.clone(), project_logo, project_description, website, milestones, required_funds, currency_id)?;
-        //schedule round
-        Proposals::<T>::schedule_round(RawOrigin::Root.into(), start_block, end_block, project_key, RoundType::ContributionRound)?;
-        //progress the blocks - to a block after the round start block for the project
-        run_to_block::<T>(progress_block_number_contribute);
-        //contribute
-        Proposals::<T>::contribute(RawOrigin::Signed(alice.clone()).into(), 0, contribution_amount)?;
-        //Approve
-        Proposals::<T>::approve(RawOrigin::Root.into(), 0, Some(milestone_keys))?;
-        //Submit Milestone
-        //project key - 2nd argument as u32 instead of vec
-        //Milestone key - 3rd argument as u32
-        Proposals::<T>::submit_milestone(RawOrigin::Signed(bob.clone()).into(), 0, 0)?;
-        //progress the blocks - to a block after the round end block for the project
+        create_project_common::<T>(CONTRIBUTION.into(), None);
+        // The delegator is moved from a delegate to one which is about to be full
+        Proposals::<T>::delegate_votes(RawOrigin::Signed(alice.clone()).into(), Some(0), previous_delegate)?;
+        for i in 1 .. T::MaxDelegatorsPerDelegate::get() {
+            let delegator: T::AccountId = account("delegator", i, SEED);
+            Proposals::<T>::delegate_votes(RawOrigin::Signed(delegator).into(), Some(0), delegate.clone())?;
+        }
+
+    }: _(RawOrigin::Signed(alice.clone()), Some(0), delegate.clone())
+    verify {
+        assert_last_event::<T>(Event::VotesDelegated(alice, Some(0), delegate).into());
+    }
+
+    vote_on_milestone {
+        // Votes delegated on the project and on every project are cast along
+        let d in 0 .. Pallet::<T>::max_delegated_votes().min(T::MaxContributorsPerProject::get() - 1);
+
+        let (bob, contributors) = fund_project::<T>(d + 1)?;
+        let alice = contributors[0].clone();
+        delegate_to::<T>(&alice, &contributors[1..])?;
+        Proposals::<T>::submit_milestone(RawOrigin::Signed(bob).into(), 0, 0, milestone_evidence())?;
+        //progress the blocks - to a block after the voting round started
+        let progress_block_number_vote_on_milestone: <T as frame_system::Config>::BlockNumber = 4u32.into();
         run_to_block::<T>(progress_block_number_vote_on_milestone);
 
         //project key - 2nd argument as u32 instead of vec
@@ -205,117 +285,265 @@ benchmarks! {
         //approval boolean as approved - 4th argument
     }: _(RawOrigin::Signed(alice.clone()), 0, 0, true)
     verify {
-        //assert_last_event::<T>(Event::VoteComplete(alice, 0, 0, true, progress_block_number_vote_on_milestone).into());
+        assert_last_event::<T>(Event::VoteComplete(alice, 0, 0, true, progress_block_number_vote_on_milestone).into());
     }
 
+    vote_on_milestone_with_conviction {
+        let d in 0 .. Pallet::<T>::max_delegated_votes().min(T::MaxContributorsPerProject::get() - 1);
 
-    finalise_milestone_voting { 
-        let alice: T::AccountId = create_funded_user::<T>("contributor", 1, 1000);
-        let bob: T::AccountId = create_funded_user::<T>("initiator", 1, 1000);
-
-        //Setting the start block to be greater than 0 which is the current block. 
-        //This condition is checked to ensure the round being cancelled has not started yet.
-        //Benchmark seems to be starting at block 1, hence setting starting block to 2
-        let start_block: T::BlockNumber = 2u32.into();
-        let end_block: T::BlockNumber = 10u32.into();
-        let project_key: Vec<ProjectKey> = vec![0];
-        let currency_id = CurrencyId::Native;
-        let contribution_amount: BalanceOf<T> = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000 as u128);
-        let milestone_keys: Vec<MilestoneKey> = vec![0];
-        let progress_block_number_contribute: <T as frame_system::Config>::BlockNumber = 3u32.into();
-        let progress_block_number_vote_on_milestone: <T as frame_system::Config>::BlockNumber = 11u32.into();
-        
-        
-        //create project
-        create_project_common::<T>(CONTRIBUTION);
-        //Proposals::<T>::create_project(RawOrigin::Signed(caller.clone()).into(), project_name.clone(), project_logo, project_description, website, milestones, required_funds, currency_id)?;
-        //schedule round
-        Proposals::<T>::schedule_round(RawOrigin::Root.into(), start_block, end_block, project_key, RoundType::ContributionRound)?;
-        //progress the blocks - to a block after the round start block for the project
-        run_to_block::<T>(progress_block_number_contribute);
-        //contribute
-        Proposals::<T>::contribute(RawOrigin::Signed(alice.clone()).into(), 0, contribution_amount)?;
-        //Approve
-        Proposals::<T>::approve(RawOrigin::Root.into(), 0, Some(milestone_keys))?;
-        //Submit Milestone
-        //project key - 2nd argument as u32 instead of vec
-        //Milestone key - 3rd argument as u32
-        Proposals::<T>::submit_milestone(RawOrigin::Signed(bob.clone()).into(), 0, 0)?;
-        //progress the blocks - to a block after the round end block for the project
+        let (bob, contributors) = fund_project::<T>(d + 1)?;
+        let alice = contributors[0].clone();
+        delegate_to::<T>(&alice, &contributors[1..])?;
+        Proposals::<T>::submit_milestone(RawOrigin::Signed(bob).into(), 0, 0, milestone_evidence())?;
+        let progress_block_number_vote_on_milestone: <T as frame_system::Config>::BlockNumber = 4u32.into();
         run_to_block::<T>(progress_block_number_vote_on_milestone);
-        //Vote on a milestone
-        //project key - 2nd argument as u32 instead of vec
-        //Milestone key - 3rd argument as u32
-        //approval boolean as approved - 4th argument
-        Proposals::<T>::vote_on_milestone(RawOrigin::Signed(alice.clone()).into(), 0, 0, true)?;
+        let locked_amount: BalanceOf<T> = contribution_amount::<T>();
 
-        //Finalization done by contributor in this case - 1st argument
-        //project key - 2nd argument
-        //milestone key - 3rd argument
-    }: _(RawOrigin::Signed(bob.clone()), 0, 0)
+    }: _(RawOrigin::Signed(alice.clone()), 0, 0, true, Conviction::Locked6x, locked_amount)
     verify {
-       //assert_last_event::<T>(Event::MilestoneApproved(0,0,progress_block_number_vote_on_milestone).into());
+        assert_last_event::<T>(Event::VoteComplete(alice, 0, 0, true, progress_block_number_vote_on_milestone).into());
     }
 
-      withdraw {
-        let alice: T::AccountId = create_funded_user::<T>("contributor", 1, 1000);
-        let bob: T::AccountId = create_funded_user::<T>("initiator", 1, 1000);
+    change_milestone_vote {
+        // The votes cast for the delegators move along
+        let d in 0 .. Pallet::<T>::max_delegated_votes().min(T::MaxContributorsPerProject::get() - 1);
 
-        //Setting the start block to be greater than 0 which is the current block.
-        //This condition is checked to ensure the round being cancelled has not started yet.
-        //Benchmark seems to be starting at block 1, hence setting starting block to 2
-        let start_block: T::BlockNumber = 0u32.into();
-        let end_block: T::BlockNumber = 10u32.into();
-        let project_key: Vec<ProjectKey> = vec![0];
-        let currency_id = CurrencyId::Native;
-        let contribution_amount: BalanceOf<T> = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000 as u128);
-        let milestone_keys: Vec<MilestoneKey> = vec![0];
-        let progress_block_number_contribute: <T as frame_system::Config>::BlockNumber = 3u32.into();
-        let progress_block_number_vote_on_milestone: <T as frame_system::Config>::BlockNumber = 5u32.into();
-        let required_funds: BalanceOf<T> = 100u32.into();
+        let (bob, contributors) = fund_project::<T>(d + 1)?;
+        let alice = contributors[0].clone();
+        delegate_to::<T>(&alice, &contributors[1..])?;
+        Proposals::<T>::submit_milestone(RawOrigin::Signed(bob).into(), 0, 0, milestone_evidence())?;
+        let progress_block_number_vote_on_milestone: <T as frame_system::Config>::BlockNumber = 4u32.into();
+        run_to_block::<T>(progress_block_number_vote_on_milestone);
+        Proposals::<T>::vote_on_milestone(RawOrigin::Signed(alice.clone()).into(), 0, 0, true)?;
+
+    }: _(RawOrigin::Signed(alice.clone()), 0, 0, false)
+    verify {
+        assert_last_event::<T>(Event::VoteChanged(alice, 0, 0, Some(false), progress_block_number_vote_on_milestone).into());
+    }
 
+    finalise_milestone_voting {
+        // Finalising clears the snapshot of every contribution
+        let c in 1 .. T::MaxContributorsPerProject::get();
 
-        //create project
-        create_project_common::<T>(CONTRIBUTION);
-        //Proposals::<T>::create_project(RawOrigin::Signed(caller.clone()).into(), project_name.clone(), project_logo, project_description, website, milestones, required_funds, currency_id)?;
-        //schedule round
-        Proposals::<T>::schedule_round(RawOrigin::Root.into(), start_block, end_block, project_key, RoundType::ContributionRound)?;
-        //progress the blocks - to a block after the round start block for the project
-        run_to_block::<T>(progress_block_number_contribute);
-        //contribute
-        Proposals::<T>::contribute(RawOrigin::Signed(alice.clone()).into(), 0, contribution_amount)?;
-        //Approve
-        Proposals::<T>::approve(RawOrigin::Root.into(), 0, Some(milestone_keys))?;
-        //Submit Milestone
-        //project key - 2nd argument as u32 instead of vec
-        //Milestone key - 3rd argument as u32
-        Proposals::<T>::submit_milestone(RawOrigin::Signed(bob.clone()).into(), 0, 0)?;
-        //progress the blocks - to a block after the round end block for the project
+        let (bob, contributors) = fund_project::<T>(c)?;
+        Proposals::<T>::submit_milestone(RawOrigin::Signed(bob.clone()).into(), 0, 0, milestone_evidence())?;
+        let progress_block_number_vote_on_milestone: <T as frame_system::Config>::BlockNumber = 4u32.into();
         run_to_block::<T>(progress_block_number_vote_on_milestone);
         //Vote on a milestone
-        //project key - 2nd argument as u32 instead of vec
-        //Milestone key - 3rd argument as u32
-        //approval boolean as approved - 4th argumentEndBlockNumberInvalid
-        Proposals::<T>::vote_on_milestone(RawOrigin::Signed(alice.clone()).into(), 0, 0, true)?;
-        //Finalizing a milestone done
-        //initiator or admin, in this case initiator - 1st argument
+        for contributor in contributors.iter() {
+            Proposals::<T>::vote_on_milestone(RawOrigin::Signed(contributor.clone()).into(), 0, 0, true)?;
+        }
+
+        //Finalization done by the initiator in this case - 1st argument
         //project key - 2nd argument
         //milestone key - 3rd argument
-        Proposals::<T>::finalise_milestone_voting(RawOrigin::Signed(bob.clone()).into(),0,0)?;
+    }: _(RawOrigin::Signed(bob.clone()), 0, 0)
+    verify {
+        assert!(Proposals::<T>::milestone_votes((0, 0)).unwrap().is_approved);
+    }
+
+    vote_of_no_confidence {
+        // The deciding vote refunds every contribution
+        let c in 1 .. T::MaxContributorsPerProject::get();
+
+        let (_, contributors) = fund_project::<T>(c)?;
+        let alice = contributors[0].clone();
+        if c > 1 {
+            Proposals::<T>::raise_vote_of_no_confidence(RawOrigin::Signed(contributors[1].clone()).into(), 0)?;
+            for contributor in contributors[2..].iter() {
+                Proposals::<T>::vote_on_no_confidence_round(RawOrigin::Signed(contributor.clone()).into(), 0, true)?;
+            }
+        }
+
+    }: {
+        if c > 1 {
+            Proposals::<T>::vote_on_no_confidence_round(RawOrigin::Signed(alice).into(), 0, true)?;
+        } else {
+            Proposals::<T>::raise_vote_of_no_confidence(RawOrigin::Signed(alice).into(), 0)?;
+        }
+    }
+    verify {
+        assert_last_event::<T>(Event::NoConfidenceRoundFinalised(0).into());
+    }
+
+    vote_on_overdue_milestone {
+        // The deciding vote refunds every contribution
+        let c in 1 .. T::MaxContributorsPerProject::get();
+
+        create_project_common::<T>(CONTRIBUTION.into(), Some(5));
+        schedule_contribution_round::<T>(0)?;
+        run_to_block::<T>(3u32.into());
+        let contributors = contribute_to_project::<T>(0, c)?;
+        // The milestone is overdue as soon as the project is funded
+        run_to_block::<T>(6u32.into());
+        Proposals::<T>::approve(T::AdminOrigin::successful_origin(), 0, None)?;
+        for contributor in contributors[1..].iter() {
+            Proposals::<T>::vote_on_overdue_milestone(RawOrigin::Signed(contributor.clone()).into(), 0, 0, true)?;
+        }
+
+    }: _(RawOrigin::Signed(contributors[0].clone()), 0, 0, true)
+    verify {
+        assert!(Proposals::<T>::projects(0).unwrap().cancelled);
+    }
+
+    withdraw {
+        let (bob, _) = fund_project::<T>(1)?;
+        let milestone_keys: BoundedMilestoneKeys = vec![0].try_into().unwrap();
+        Proposals::<T>::approve(T::AdminOrigin::successful_origin(), 0, Some(milestone_keys))?;
+        let withdrawn_funds = Proposals::<T>::get_withdrawable_funds(0).map_err(DispatchError::from)?;
 
         // Withdraw method takes the project initiator and the project id for which user wants to withdraw the funds for
     }: _(RawOrigin::Signed(bob.clone()), 0)
     verify {
-        //assert_last_event::<T>(Event::ProjectFundsWithdrawn(bob,0,required_funds,currency_id).into());
+        assert_last_event::<T>(Event::ProjectFundsWithdrawn(bob, 0, withdrawn_funds, CurrencyId::Native).into());
+    }
+
+    claim_vested_funds {
+        let bob: T::AccountId = create_project_common::<T>(CONTRIBUTION.into(), None);
+        let milestone_keys: BoundedMilestoneKeys = vec![0].try_into().unwrap();
+        Proposals::<T>::set_vesting_period(RawOrigin::Signed(bob.clone()).into(), 0, Some(10u32.into()))?;
+        schedule_contribution_round::<T>(0)?;
+        run_to_block::<T>(3u32.into());
+        contribute_to_project::<T>(0, 1)?;
+        Proposals::<T>::approve(T::AdminOrigin::successful_origin(), 0, Some(milestone_keys))?;
+        Proposals::<T>::withdraw(RawOrigin::Signed(bob.clone()).into(), 0)?;
+        // Half of the withdrawal vested
+        run_to_block::<T>(8u32.into());
+        let claimable_funds = Proposals::<T>::get_claimable_funds(0);
+
+    }: _(RawOrigin::Signed(bob.clone()), 0)
+    verify {
+        assert_last_event::<T>(Event::VestedFundsClaimed(bob, 0, claimable_funds, CurrencyId::Native).into());
+    }
+
+    set_max_proposal_count_per_round {
+        let s in 1 .. T::MaxProposalsPerRound::get();
+
+        let origin = T::AdminOrigin::successful_origin();
+    }: _<T::Origin>(origin, s)
+    verify {
+        assert_eq!(Proposals::<T>::max_proposal_count_per_round(), s);
+    }
+
+    set_milestone_voting_quorum {
+        let origin = T::AdminOrigin::successful_origin();
+    }: _<T::Origin>(origin, Perbill::from_percent(60))
+    verify {
+        assert_eq!(Proposals::<T>::milestone_voting_quorum(), Perbill::from_percent(60));
+    }
+
+    set_funding_soft_cap {
+        let origin = T::AdminOrigin::successful_origin();
+    }: _<T::Origin>(origin, Perbill::from_percent(60))
+    verify {
+        assert_eq!(Proposals::<T>::funding_soft_cap(), Perbill::from_percent(60));
+    }
+
+    set_milestone_approval_threshold {
+        let origin = T::AdminOrigin::successful_origin();
+    }: _<T::Origin>(origin, Perbill::from_percent(60))
+    verify {
+        assert_eq!(Proposals::<T>::milestone_approval_threshold(), Perbill::from_percent(60));
+    }
+
+    set_withdrawal_expiration {
+        let origin = T::AdminOrigin::successful_origin();
+        let withdrawal_expiration = T::MaxWithdrawalExpiration::get();
+    }: _<T::Origin>(origin, withdrawal_expiration)
+    verify {
+        assert_eq!(Proposals::<T>::withdrawal_expiration(), withdrawal_expiration);
+    }
+
+    set_is_identity_required {
+        let origin = T::AdminOrigin::successful_origin();
+    }: _<T::Origin>(origin, true)
+    verify {
+        assert!(Proposals::<T>::is_identity_required());
+    }
+
+    reject_project {
+        create_project_common::<T>(CONTRIBUTION.into(), None);
+        let origin = T::AdminOrigin::successful_origin();
+    }: _<T::Origin>(origin, 0)
+    verify {
+        assert!(Proposals::<T>::projects(0).unwrap().cancelled);
+    }
+
+    refund {
+        let c in 1 .. T::MaxContributorsPerProject::get();
+
+        fund_project::<T>(c)?;
+        let origin = T::RefundOrigin::successful_origin();
+    }: _<T::Origin>(origin, 0)
+    verify {
+        assert!(Proposals::<T>::projects(0).unwrap().cancelled);
+    }
+
+    // A single refund of a cancelled round, refunds are processed one by one in on_idle
+    refund_contribution {
+        let alice: T::AccountId = create_funded_user::<T>("candidate", 1, 1000);
+        let contribution_amount: BalanceOf<T> = contribution_amount::<T>();
+
+        create_project_common::<T>(CONTRIBUTION.into(), None);
+        schedule_contribution_round::<T>(0)?;
+        run_to_block::<T>(3u32.into());
+        Proposals::<T>::contribute(RawOrigin::Signed(alice.clone()).into(), 0, contribution_amount)?;
+        RoundRefundQueue::<T>::insert((0, 0), ());
+
+    }: {
+        Proposals::<T>::process_round_refunds(Weight::max_value());
+    }
+    verify {
+        assert_last_event::<T>(Event::ContributionRefunded(0, 0, alice, contribution_amount).into());
+    }
+
+    distribute_matching_pool {
+        let s in 1 .. T::MaxProposalsPerRound::get();
+        // The round contributions of every project are summed up
+        let c in 1 .. T::MaxContributorsPerProject::get();
+
+        MaxProposalCountPerRound::<T>::put(T::MaxProposalsPerRound::get());
+        let sponsor: T::AccountId = create_funded_user::<T>("sponsor", 1, 1000);
+        for _ in 0 .. s {
+            create_project_common::<T>(CONTRIBUTION.into(), None);
+        }
+        run_to_block::<T>(1u32.into());
+        let project_keys: BoundedProjectKeys = (0 .. s).collect::<Vec<ProjectKey>>().try_into().unwrap();
+        Proposals::<T>::schedule_round(T::RoundOrigin::successful_origin(), 2u32.into(), 10u32.into(), project_keys, RoundType::ContributionRound)?;
+        run_to_block::<T>(3u32.into());
+        Proposals::<T>::fund_matching_pool(RawOrigin::Signed(sponsor).into(), 0, CurrencyId::Native, contribution_amount::<T>())?;
+        // Every project of the round is funded and matched
+        for project_key in 0 .. s {
+            contribute_to_project::<T>(project_key, c)?;
+            Proposals::<T>::approve(T::AdminOrigin::successful_origin(), project_key, None)?;
+        }
+        run_to_block::<T>(11u32.into());
+
+    }: {
+        Proposals::<T>::distribute_matching_pool(0)?;
+    }
+    verify {
+        assert!(Proposals::<T>::matching_pools(0).is_none());
+    }
+
+    reclaim_expired_funds {
+        // The expired funds may be shared between every contributor
+        let c in 1 .. T::MaxContributorsPerProject::get();
+
+        fund_project::<T>(c)?;
+        let milestone_keys: BoundedMilestoneKeys = vec![0].try_into().unwrap();
+        Proposals::<T>::approve(T::AdminOrigin::successful_origin(), 0, Some(milestone_keys))?;
+
+    }: {
+        Proposals::<T>::reclaim_expired_funds(0)?;
+    }
+    verify {
+        assert_eq!(Proposals::<T>::get_withdrawable_funds(0).map_err(DispatchError::from)?, (0_u32).into());
     }
 
 }
 
-impl_benchmark_test_suite!(
-    Pallet,
-    crate::mock::TestExternalitiesBuilder::default().build(|| {}),
-    crate::mock::MockRuntime,
-);
+impl_benchmark_test_suite!(Proposals, crate::mock::ExtBuilder.build(), crate::mock::Test);
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event)
 where
@@ -328,25 +556,83 @@ where
     assert_eq!(event, &system_event);
 }
 
-fn create_project_common<T: Config>(contribution: u32) {
-        let _caller: T::AccountId = whitelisted_caller();
+fn milestone_evidence() -> BoundedEvidenceField {
+    b"ipfs://QmMilestoneEvidence".to_vec().try_into().unwrap()
+}
+
+fn proposed_milestone(percentage_to_unlock: u32, deadline: Option<u32>) -> ProposedMilestone {
+    ProposedMilestone {
+        name: b"milestone".to_vec().try_into().unwrap(),
+        percentage_to_unlock,
+        deadline,
+    }
+}
+
+fn contribution_amount<T: Config>() -> BalanceOf<T> {
+    BalanceOf::<T>::unique_saturated_from(1_000_000_000_000 as u128)
+}
+
+/// Create a project with a single milestone, due at `deadline` if any, and return its initiator.
+fn create_project_common<T: Config>(required_funds: BalanceOf<T>, deadline: Option<u32>) -> T::AccountId {
         let bob: T::AccountId = create_funded_user::<T>("initiator", 1, 1000);
-        let project_name: Vec<u8> = str::from_utf8(b"Imbue's Awesome Initiative").unwrap().as_bytes().to_vec();
-        let project_logo: Vec<u8> = str::from_utf8(b"Imbue Logo").unwrap().as_bytes().to_vec();
-        let project_description: Vec<u8> = str::from_utf8(b"This project is aimed at promoting Decentralised Data and Transparent Crowdfunding.").unwrap().as_bytes().to_vec();
-        let website: Vec<u8> = str::from_utf8(b"https://imbue.network").unwrap().as_bytes().to_vec();
-        let milestones: Vec<ProposedMilestone> = vec![ProposedMilestone {
-            name: Vec::new(),
-            percentage_to_unlock: 100,
-        }];
-
-        let required_funds: BalanceOf<T> = contribution.into();
+        let project_name: BoundedStringField = str::from_utf8(b"Imbue's Awesome Initiative").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let project_logo: BoundedStringField = str::from_utf8(b"Imbue Logo").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let project_description: BoundedDescriptionField = str::from_utf8(b"This project is aimed at promoting Decentralised Data and Transparent Crowdfunding.").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let website: BoundedDescriptionField = str::from_utf8(b"https://imbue.network").unwrap().as_bytes().to_vec().try_into().unwrap();
+        let milestones: BoundedProposedMilestones = vec![proposed_milestone(100, deadline)].try_into().unwrap();
+
         let currency_id = CurrencyId::Native;
-        
-        let _start_block: T::BlockNumber = 0u32.into();
 
-        let _ =Proposals::<T>::create_project(RawOrigin::Signed(bob.clone()).into(), project_name.clone(), project_logo, project_description, website, milestones, required_funds, currency_id);
-        
+        let _ =Proposals::<T>::create_project(RawOrigin::Signed(bob.clone()).into(), project_name, project_logo, project_description, website, milestones, required_funds, currency_id);
+        bob
+}
+
+/// Schedule a contribution round for a project which is open from block 3 until block 10.
+fn schedule_contribution_round<T: Config>(project_key: ProjectKey) -> Result<(), BenchmarkError> {
+    //Setting the start block to be greater than the current block.
+    run_to_block::<T>(1u32.into());
+    let start_block: T::BlockNumber = 2u32.into();
+    let end_block: T::BlockNumber = 10u32.into();
+    let project_keys: BoundedProjectKeys = vec![project_key].try_into().unwrap();
+    Proposals::<T>::schedule_round(T::RoundOrigin::successful_origin(), start_block, end_block, project_keys, RoundType::ContributionRound)?;
+    Ok(())
+}
+
+/// Contribute to a project from `c` accounts.
+/// The first contributor holds nearly all of the contributions, so its vote alone decides.
+fn contribute_to_project<T: Config>(project_key: ProjectKey, c: u32) -> Result<Vec<T::AccountId>, BenchmarkError> {
+    let mut contributors = Vec::new();
+    for i in 0 .. c {
+        let (contributor, value) = if i == 0 {
+            let value = contribution_amount::<T>() * (100 * c).into();
+            (create_funded_user::<T>("contributor", i, 1000 + 100 * c), value)
+        } else {
+            (create_funded_user::<T>("contributor", i, 1000), contribution_amount::<T>())
+        };
+        Proposals::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), project_key, value)?;
+        contributors.push(contributor);
+    }
+    Ok(contributors)
+}
+
+/// Create a project, fund it by `c` contributors and approve it, leaving the chain at block 3.
+fn fund_project<T: Config>(c: u32) -> Result<(T::AccountId, Vec<T::AccountId>), BenchmarkError> {
+    let bob: T::AccountId = create_project_common::<T>(CONTRIBUTION.into(), None);
+    schedule_contribution_round::<T>(0)?;
+    run_to_block::<T>(3u32.into());
+    let contributors = contribute_to_project::<T>(0, c)?;
+    Proposals::<T>::approve(T::AdminOrigin::successful_origin(), 0, None)?;
+    Ok((bob, contributors))
+}
+
+/// Delegate the votes of the delegators to `delegate`, on every project as long as
+/// the delegate takes them and on the project otherwise.
+fn delegate_to<T: Config>(delegate: &T::AccountId, delegators: &[T::AccountId]) -> Result<(), BenchmarkError> {
+    for (i, delegator) in delegators.iter().enumerate() {
+        let project_key = if (i as u32) < T::MaxDelegatorsPerDelegate::get() { None } else { Some(0) };
+        Proposals::<T>::delegate_votes(RawOrigin::Signed(delegator.clone()).into(), project_key, delegate.clone())?;
+    }
+    Ok(())
 }
 
 fn run_to_block<T: Config>(new_block: <T as frame_system::Config>::BlockNumber) {
@@ -359,10 +645,7 @@ fn create_funded_user<T: Config>(
 	balance_factor: u32,
 ) -> T::AccountId {
 	let user = account(string, n, SEED);
-	let balance = T::Currency::minimum_balance() * balance_factor.into();
-	let _ = T::Currency::make_free_balance_be(&user, balance);
+	let balance = contribution_amount::<T>() * balance_factor.into();
+	let _ = T::MultiCurrency::deposit(CurrencyId::Native, &user, balance);
 	user
 }
-
-
-impl_benchmark_test_suite!(Proposals, crate::mock::new_test_ext(), crate::mock::Test);
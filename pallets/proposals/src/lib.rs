@@ -10,27 +10,41 @@ use frame_support::{
     pallet_prelude::*,
     transactional,
     PalletId, 
-    traits::ConstU32
+    traits::{ConstU32, Currency, LockIdentifier, LockableCurrency, WithdrawReasons}
     };
-use orml_traits::MultiCurrency;
+use orml_traits::{BalanceStatus, MultiCurrency, MultiReservableCurrency, NamedMultiReservableCurrency};
 pub use pallet::*;
 use scale_info::TypeInfo;
-use sp_runtime::traits::AccountIdConversion;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+    traits::{AccountIdConversion, Saturating, Zero},
+    Perbill, SaturatedConversion,
+};
 use sp_std::{
     convert::TryInto,
     prelude::*,
     vec
 };
 use frame_system::pallet_prelude::*;
+use integer_sqrt::IntegerSquareRoot;
 #[cfg(test)]
 mod mock;
 
+/// The lock on the native funds backing conviction votes.
+const CONVICTION_VOTE_LOCK_ID: LockIdentifier = *b"imbu/cvl";
+
+/// The prefix of the named reserves holding contributions, followed by the project key.
+const CONTRIBUTION_RESERVE_PREFIX: [u8; 4] = *b"imbc";
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
+
 pub mod weights;
 pub use weights::*;
 
@@ -46,12 +60,81 @@ pub mod pallet {
 
         type PalletId: Get<PalletId>;
 
-        type MultiCurrency: MultiCurrency<AccountIdOf<Self>, CurrencyId = CurrencyId>;
+        type MultiCurrency: NamedMultiReservableCurrency<
+            AccountIdOf<Self>,
+            CurrencyId = CurrencyId,
+            ReserveIdentifier = [u8; 8],
+        >;
 
         type MaxProposalsPerRound: Get<u32>;
 
         type MaxWithdrawalExpiration: Get<Self::BlockNumber>;
 
+        /// The maximum number of milestone votes finalised automatically in a single block.
+        type MaxMilestoneFinalisationsPerBlock: Get<u32>;
+
+        /// Where the funds which were not withdrawn before the withdrawal expiration go.
+        type ExpiredFundsDestination: Get<ExpiredFundsDestination>;
+
+        type TreasuryAccount: Get<AccountIdOf<Self>>;
+
+        /// The native deposit reserved for storing an item, e.g. a project or a whitelist spot.
+        type DepositPerItem: Get<BalanceOf<Self>>;
+
+        /// The native deposit reserved for every byte of a project or a whitelist.
+        type DepositPerByte: Get<BalanceOf<Self>>;
+
+        /// The share of the contributions needed for a vote of no confidence to refund a project.
+        type NoConfidenceThreshold: Get<Perbill>;
+
+        /// How long contributors can vote in a vote of no confidence once it was raised.
+        type NoConfidenceVotingPeriod: Get<Self::BlockNumber>;
+
+        /// How much longer contributors can give an overdue milestone when they vote to extend it.
+        type MilestoneDeadlineExtension: Get<Self::BlockNumber>;
+
+        /// The vote on an overdue milestone is settled once more than this share of the
+        /// contributions voted to refund, or to extend the deadline.
+        type OverdueMilestoneMajority: Get<Perbill>;
+
+        /// How long the initiator has to wait before resubmitting a rejected milestone.
+        type MilestoneResubmissionCooldown: Get<Self::BlockNumber>;
+
+        /// How many times a milestone can be resubmitted after its first submission.
+        type MaxMilestoneResubmissions: Get<u32>;
+
+        /// The native currency locked for conviction votes.
+        type NativeCurrency: LockableCurrency<
+            AccountIdOf<Self>,
+            Moment = Self::BlockNumber,
+            Balance = BalanceOf<Self>,
+        >;
+
+        /// How long the funds backing a conviction vote stay locked for each conviction period.
+        type ConvictionVoteLockingPeriod: Get<Self::BlockNumber>;
+
+        /// How many conviction locks an account can hold at once.
+        type MaxConvictionLocks: Get<u32>;
+
+        /// How many contributors can delegate their milestone votes to a delegate,
+        /// on a single project and on every project each.
+        type MaxDelegatorsPerDelegate: Get<u32>;
+
+        /// How many accounts can contribute to a project, this bounds the milestone vote snapshots.
+        type MaxContributorsPerProject: Get<u32>;
+
+        /// How many members a project team can have, they are removed when the ownership changes.
+        type MaxTeamMembersPerProject: Get<u32>;
+
+        /// The origin allowed to approve projects and change the pallet settings.
+        type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+        /// The origin allowed to schedule, edit and cancel rounds.
+        type RoundOrigin: EnsureOrigin<Self::Origin>;
+
+        /// The origin allowed to refund the locked funds of a project.
+        type RefundOrigin: EnsureOrigin<Self::Origin>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -62,8 +145,35 @@ pub mod pallet {
         100800u32
     }
 
+    #[pallet::type_value]
+    pub fn InitialMilestoneVotingQuorum() -> Perbill
+    {
+        Perbill::one()
+    }
+
+    #[pallet::type_value]
+    pub fn InitialFundingSoftCap() -> Perbill
+    {
+        Perbill::zero()
+    }
+
+    #[pallet::type_value]
+    pub fn InitialMilestoneApprovalThreshold() -> Perbill
+    {
+        Perbill::from_percent(50)
+    }
+
+    #[pallet::type_value]
+    pub fn InitialMaxProposalCountPerRound<T: Config>() -> u32
+    {
+        T::MaxProposalsPerRound::get()
+    }
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
@@ -77,6 +187,55 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn contributions)]
+    pub type Contributions<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        ProjectKey,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// The sum of all `Contributions` of a project, kept up to date on every change.
+    #[pallet::storage]
+    #[pallet::getter(fn project_contributions_total)]
+    pub type ProjectContributionsTotal<T: Config> =
+        StorageMap<_, Identity, ProjectKey, BalanceOf<T>, ValueQuery>;
+
+    /// The matching funds a project received, released with its milestones like contributions.
+    #[pallet::storage]
+    #[pallet::getter(fn project_matched_funds)]
+    pub type ProjectMatchedFunds<T> = StorageMap<_, Identity, ProjectKey, BalanceOf<T>, ValueQuery>;
+
+    /// The matching pool of a contribution round, distributed once the round ends.
+    #[pallet::storage]
+    #[pallet::getter(fn matching_pools)]
+    pub type MatchingPools<T> =
+        StorageMap<_, Identity, RoundKey, MatchingPool<BalanceOf<T>>, OptionQuery>;
+
+    /// What each sponsor put into the matching pool of a round.
+    #[pallet::storage]
+    #[pallet::getter(fn matching_pool_sponsors)]
+    pub type MatchingPoolSponsors<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        RoundKey,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// The matching pools of the rounds ending at a block.
+    /// Distributed in `on_initialize` at that block.
+    #[pallet::storage]
+    #[pallet::getter(fn matching_pools_ending_at)]
+    pub type MatchingPoolsEndingAt<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<RoundKey>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn whitelist_spots)]
     pub type WhitelistSpots<T: Config> = StorageMap<
@@ -97,11 +256,234 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// The account a contributor delegated its milestone votes on a project to.
+    #[pallet::storage]
+    #[pallet::getter(fn project_vote_delegations)]
+    pub type ProjectVoteDelegations<T: Config> =
+        StorageDoubleMap<_, Identity, ProjectKey, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// The account a contributor delegated its milestone votes on every project to,
+    /// unless a delegate is set for the project itself.
+    #[pallet::storage]
+    #[pallet::getter(fn vote_delegations)]
+    pub type VoteDelegations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// The contributors which delegated their milestone votes on a project to a delegate.
+    #[pallet::storage]
+    #[pallet::getter(fn project_delegators)]
+    pub type ProjectDelegators<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        ProjectKey,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxDelegatorsPerDelegate>,
+        ValueQuery,
+    >;
+
+    /// The contributors which delegated their milestone votes on every project to a delegate.
+    #[pallet::storage]
+    #[pallet::getter(fn delegators)]
+    pub type Delegators<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxDelegatorsPerDelegate>,
+        ValueQuery,
+    >;
+
+    /// The contributors whose milestone vote was cast by their delegate.
+    #[pallet::storage]
+    #[pallet::getter(fn delegated_votes)]
+    pub type DelegatedVotes<T: Config> = StorageMap<
+        _,
+        Identity,
+        (T::AccountId, ProjectKey, MilestoneKey, RoundKey),
+        Vec<T::AccountId>,
+        ValueQuery,
+    >;
+
+    /// The conviction milestone votes were cast with and the native funds locked for it,
+    /// votes without conviction are not stored.
+    #[pallet::storage]
+    #[pallet::getter(fn user_vote_convictions)]
+    pub type UserVoteConvictions<T: Config> = StorageMap<
+        _,
+        Identity,
+        (T::AccountId, ProjectKey, MilestoneKey, RoundKey),
+        (Conviction, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// The native funds an account locked for conviction votes and the block each lock ends at.
+    /// The account stays locked for the largest amount until every lock ended.
+    #[pallet::storage]
+    #[pallet::getter(fn conviction_locks)]
+    pub type ConvictionLocks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(T::BlockNumber, BalanceOf<T>), T::MaxConvictionLocks>,
+        ValueQuery,
+    >;
+
+    /// The accounts with a conviction lock ending at a block, released in `on_initialize`.
+    #[pallet::storage]
+    #[pallet::getter(fn conviction_locks_expiring_at)]
+    pub type ConvictionLocksExpiringAt<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<T::AccountId>, ValueQuery>;
+
+    /// The delegate which cast the milestone vote of a contributor.
+    #[pallet::storage]
+    #[pallet::getter(fn vote_cast_by)]
+    pub type VoteCastBy<T: Config> = StorageMap<
+        _,
+        Identity,
+        (T::AccountId, ProjectKey, MilestoneKey, RoundKey),
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// The voting weight of every contributor in a milestone voting round, taken when the
+    /// milestone was submitted and cleared once the vote is closed.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_vote_snapshots)]
+    pub type MilestoneVoteSnapshots<T: Config> =
+        StorageDoubleMap<_, Identity, RoundKey, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+    /// The total voting weight and the number of voters of a milestone voting round,
+    /// taken together with `MilestoneVoteSnapshots`.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_vote_snapshot_totals)]
+    pub type MilestoneVoteSnapshotTotals<T> =
+        StorageMap<_, Identity, RoundKey, (BalanceOf<T>, u32), OptionQuery>;
+
+    /// The number of accounts with a contribution to a project.
+    #[pallet::storage]
+    #[pallet::getter(fn project_contributor_count)]
+    pub type ProjectContributorCount<T> = StorageMap<_, Identity, ProjectKey, u32, ValueQuery>;
+
+    /// The evidence of every submission of a milestone, the latest submission comes last.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_evidence)]
+    pub type MilestoneEvidence<T> =
+        StorageMap<_, Identity, (ProjectKey, MilestoneKey), Vec<Vec<u8>>, ValueQuery>;
+
+    /// Every submission of a milestone and its outcome, the latest submission comes last.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_submissions)]
+    pub type MilestoneSubmissions<T: Config> = StorageMap<
+        _,
+        Identity,
+        (ProjectKey, MilestoneKey),
+        Vec<MilestoneSubmission<BalanceOf<T>, T::BlockNumber>>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn milestone_votes)]
     pub(super) type MilestoneVotes<T: Config> =
         StorageMap<_, Identity, (ProjectKey, MilestoneKey), Vote<BalanceOf<T>>, OptionQuery>;
 
+    /// The deposit reserved from the initiator for storing a project.
+    #[pallet::storage]
+    #[pallet::getter(fn project_deposits)]
+    pub type ProjectDeposits<T> = StorageMap<_, Identity, ProjectKey, BalanceOf<T>, ValueQuery>;
+
+    /// The deposit reserved from the initiator for storing the whitelist of a project.
+    #[pallet::storage]
+    #[pallet::getter(fn whitelist_deposits)]
+    pub type WhitelistDeposits<T> = StorageMap<_, Identity, ProjectKey, BalanceOf<T>, ValueQuery>;
+
+    /// The account a project initiator proposed to hand the project over to.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_ownership_transfers)]
+    pub type PendingOwnershipTransfers<T: Config> =
+        StorageMap<_, Identity, ProjectKey, T::AccountId, OptionQuery>;
+
+    /// The team members of a project and what they are allowed to do.
+    #[pallet::storage]
+    #[pallet::getter(fn project_team)]
+    pub type ProjectTeam<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        ProjectKey,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<ProjectRole>,
+        OptionQuery,
+    >;
+
+    /// The number of members in the team of a project.
+    #[pallet::storage]
+    #[pallet::getter(fn project_team_size)]
+    pub type ProjectTeamSize<T> = StorageMap<_, Identity, ProjectKey, u32, ValueQuery>;
+
+    /// The account withdrawals of a project go to, instead of the initiator.
+    #[pallet::storage]
+    #[pallet::getter(fn project_beneficiaries)]
+    pub type ProjectBeneficiaries<T: Config> =
+        StorageMap<_, Identity, ProjectKey, T::AccountId, OptionQuery>;
+
+    /// Projects whose contributions stay reserved on the contributors' accounts until funded.
+    #[pallet::storage]
+    #[pallet::getter(fn reserve_contributions)]
+    pub type ReserveContributions<T> = StorageMap<_, Identity, ProjectKey, bool, ValueQuery>;
+
+    /// The soft cap, hard cap and overfunding policy of a project.
+    #[pallet::storage]
+    #[pallet::getter(fn project_funding_caps)]
+    pub type ProjectFundingCaps<T> =
+        StorageMap<_, Identity, ProjectKey, FundingCaps<BalanceOf<T>>, OptionQuery>;
+
+    /// The number of blocks withdrawals of a project vest over, instead of being paid out at once.
+    #[pallet::storage]
+    #[pallet::getter(fn project_vesting_periods)]
+    pub type ProjectVestingPeriods<T: Config> =
+        StorageMap<_, Identity, ProjectKey, T::BlockNumber, OptionQuery>;
+
+    /// The withdrawals of a project which are still vesting, claimed through `claim_vested_funds`.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedules)]
+    pub type VestingSchedules<T: Config> = StorageMap<
+        _,
+        Identity,
+        ProjectKey,
+        Vec<VestingSchedule<BalanceOf<T>, T::BlockNumber>>,
+        ValueQuery,
+    >;
+
+    /// The open vote of no confidence of a project, `yay` votes are in favour of a refund.
+    #[pallet::storage]
+    #[pallet::getter(fn no_confidence_votes)]
+    pub type NoConfidenceVotes<T> =
+        StorageMap<_, Identity, ProjectKey, Vote<BalanceOf<T>>, OptionQuery>;
+
+    /// The block the open vote of no confidence of a project ends at.
+    #[pallet::storage]
+    #[pallet::getter(fn no_confidence_round_ends)]
+    pub type NoConfidenceRoundEnds<T: Config> =
+        StorageMap<_, Identity, ProjectKey, T::BlockNumber, OptionQuery>;
+
+    /// The votes of no confidence ending at a block.
+    /// Expired in `on_initialize` once the block has passed.
+    #[pallet::storage]
+    #[pallet::getter(fn no_confidence_votes_ending_at)]
+    pub type NoConfidenceVotesEndingAt<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<ProjectKey>, ValueQuery>;
+
+    /// How each contributor voted in a vote of no confidence, keyed by the block it ends at.
+    #[pallet::storage]
+    #[pallet::getter(fn user_no_confidence_votes)]
+    pub(super) type UserNoConfidenceVotes<T: Config> = StorageMap<
+        _,
+        Identity,
+        (T::AccountId, ProjectKey, T::BlockNumber),
+        bool,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn project_count)]
     pub type ProjectCount<T> = StorageValue<_, ProjectKey, ValueQuery>;
@@ -110,26 +492,135 @@ pub mod pallet {
     #[pallet::getter(fn rounds)]
     pub type Rounds<T> = StorageMap<_, Identity, RoundKey, Option<RoundOf<T>>, ValueQuery>;
 
+    /// The latest round of each type a project is scheduled in, cancelled rounds excluded.
+    #[pallet::storage]
+    #[pallet::getter(fn project_rounds)]
+    pub type ProjectRounds<T> =
+        StorageDoubleMap<_, Identity, ProjectKey, Twox64Concat, RoundType, RoundKey, OptionQuery>;
+
+    /// The round of the same type a project was scheduled in before the given one,
+    /// `ProjectRounds` falls back to it when the project leaves the given round.
+    #[pallet::storage]
+    #[pallet::getter(fn project_previous_rounds)]
+    pub type ProjectPreviousRounds<T> =
+        StorageDoubleMap<_, Identity, ProjectKey, Identity, RoundKey, RoundKey, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn round_count)]
     pub type RoundCount<T> = StorageValue<_, RoundKey, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn max_proposal_count_per_round)]
-    pub type MaxProposalCountPerRound<T> = StorageValue<_, u32, ValueQuery>;
+    pub type MaxProposalCountPerRound<T: Config> =
+        StorageValue<_, u32, ValueQuery, InitialMaxProposalCountPerRound<T>>;
 
     #[pallet::storage]
     #[pallet::getter(fn milestone_voting_window)]
     pub type MilestoneVotingWindow<T> = StorageValue<_, u32, ValueQuery, InitialMilestoneVotingWindow>;
 
+    /// The share of the total contributions which must have voted before a milestone vote
+    /// can be finalised while its voting window is still open.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_voting_quorum)]
+    pub type MilestoneVotingQuorum<T> = StorageValue<_, Perbill, ValueQuery, InitialMilestoneVotingQuorum>;
+
+    /// A milestone is approved when more than this share of the cast votes are in favour.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_approval_threshold)]
+    pub type MilestoneApprovalThreshold<T> = StorageValue<_, Perbill, ValueQuery, InitialMilestoneApprovalThreshold>;
+
+    /// The share of `required_funds` a project must raise to be fundable once its round ended,
+    /// unless the project sets its own soft cap. Zero means all of `required_funds`.
+    #[pallet::storage]
+    #[pallet::getter(fn funding_soft_cap)]
+    pub type FundingSoftCap<T> = StorageValue<_, Perbill, ValueQuery, InitialFundingSoftCap>;
+
+    /// The voting round created when a milestone was submitted.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_voting_rounds)]
+    pub type MilestoneVotingRounds<T> =
+        StorageMap<_, Identity, (ProjectKey, MilestoneKey), RoundKey, OptionQuery>;
+
+    /// The milestone votes of each voting round, keyed by the block the round ends at.
+    /// Finalised automatically in `on_initialize` once the round is over.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_votes_ending_at)]
+    pub type MilestoneVotesEndingAt<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<(ProjectKey, MilestoneKey)>, ValueQuery>;
+
+    /// The block a milestone is due at, only set for milestones with a deadline.
+    #[pallet::storage]
+    #[pallet::getter(fn milestone_deadlines)]
+    pub type MilestoneDeadlines<T: Config> =
+        StorageMap<_, Identity, (ProjectKey, MilestoneKey), T::BlockNumber, OptionQuery>;
+
+    /// The milestones due at a block.
+    /// Checked in `on_initialize` once the block has passed.
+    #[pallet::storage]
+    #[pallet::getter(fn milestones_due_at)]
+    pub type MilestonesDueAt<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<(ProjectKey, MilestoneKey)>, ValueQuery>;
+
+    /// The open vote on an overdue milestone, `yay` votes are in favour of a refund
+    /// and `nay` votes in favour of extending the deadline.
+    #[pallet::storage]
+    #[pallet::getter(fn overdue_milestone_votes)]
+    pub type OverdueMilestoneVotes<T> =
+        StorageMap<_, Identity, (ProjectKey, MilestoneKey), Vote<BalanceOf<T>>, OptionQuery>;
+
+    /// How each contributor voted on an overdue milestone, keyed by the deadline it missed.
+    #[pallet::storage]
+    #[pallet::getter(fn user_overdue_milestone_votes)]
+    pub(super) type UserOverdueMilestoneVotes<T: Config> = StorageMap<
+        _,
+        Identity,
+        (T::AccountId, ProjectKey, MilestoneKey, T::BlockNumber),
+        bool,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn withdrawal_expiration)]
     pub type WithdrawalExpiration<T> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+    /// The last block at which the initiator can withdraw the funds of approved milestones.
+    #[pallet::storage]
+    #[pallet::getter(fn project_withdrawal_expiry)]
+    pub type ProjectWithdrawalExpiry<T: Config> =
+        StorageMap<_, Identity, ProjectKey, T::BlockNumber, OptionQuery>;
+
+    /// The projects whose withdrawable funds expire at a block.
+    /// Reclaimed in `on_initialize` once the block has passed.
+    #[pallet::storage]
+    #[pallet::getter(fn withdrawals_expiring_at)]
+    pub type WithdrawalsExpiringAt<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<ProjectKey>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn is_identity_required)]
     pub type IsIdentityRequired<T> = StorageValue<_, bool, ValueQuery>;
 
+    /// The contributions each account made to a project during a contribution round.
+    /// Used to refund only what was contributed to a round when it is cancelled.
+    #[pallet::storage]
+    #[pallet::getter(fn round_contributions)]
+    pub type RoundContributions<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (RoundKey, ProjectKey),
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Projects of cancelled rounds whose contributions still have to be refunded.
+    /// Drained in `on_idle` so that large rounds are refunded over several blocks.
+    #[pallet::storage]
+    #[pallet::getter(fn round_refund_queue)]
+    pub type RoundRefundQueue<T> =
+        StorageMap<_, Twox64Concat, (RoundKey, ProjectKey), (), OptionQuery>;
+
     // Pallets use events to inform users when important changes are made.
     // https://substrate.dev/docs/en/knowledgebase/runtime/events
     #[pallet::event]
@@ -142,9 +633,32 @@ pub mod pallet {
             BalanceOf<T>,
             common_types::CurrencyId,
         ),
+        ProjectUpdated(T::AccountId, ProjectKey, BalanceOf<T>),
+        OwnershipTransferProposed(ProjectKey, T::AccountId),
+        /// The project was handed over from the first account to the second.
+        OwnershipTransferred(ProjectKey, T::AccountId, T::AccountId),
+        /// The roles of a team member were changed, no roles removes the member.
+        TeamMemberUpdated(ProjectKey, T::AccountId, Vec<ProjectRole>),
+        BeneficiaryUpdated(ProjectKey, Option<T::AccountId>),
+        /// Whether contributions to the project are reserved until it is funded.
+        ContributionModeUpdated(ProjectKey, bool),
+        /// The reserved contributions were moved to the project account once it was funded.
+        ReservedContributionsTransferred(ProjectKey, BalanceOf<T>),
+        /// The reserved contributions were returned once the round ended without funding the project.
+        ReservedContributionsReleased(ProjectKey, BalanceOf<T>),
+        FundingCapsUpdated(ProjectKey, FundingCaps<BalanceOf<T>>),
+        /// The funds raised beyond `required_funds` were returned to the contributors.
+        OverfundingReturned(ProjectKey, BalanceOf<T>),
+        NoConfidenceRoundCreated(T::AccountId, ProjectKey),
+        NoConfidenceVoteComplete(T::AccountId, ProjectKey, bool, T::BlockNumber),
+        /// The vote of no confidence passed and the project was refunded.
+        NoConfidenceRoundFinalised(ProjectKey),
+        /// The vote of no confidence ended without enough votes to refund the project.
+        NoConfidenceRoundExpired(ProjectKey),
         FundingRoundCreated(RoundKey, Vec<ProjectKey>),
         VotingRoundCreated(RoundKey, Vec<ProjectKey>),
-        MilestoneSubmitted(T::AccountId, ProjectKey, MilestoneKey),
+        /// A milestone was submitted for a vote with evidence of the deliverable.
+        MilestoneSubmitted(T::AccountId, ProjectKey, MilestoneKey, Vec<u8>),
         ContributeSucceeded(
             T::AccountId,
             ProjectKey,
@@ -152,15 +666,61 @@ pub mod pallet {
             common_types::CurrencyId,
             T::BlockNumber,
         ),
+        /// The contributor took back part or all of a contribution while the round was open.
+        ContributionWithdrawn(
+            T::AccountId,
+            ProjectKey,
+            BalanceOf<T>,
+            common_types::CurrencyId,
+            T::BlockNumber,
+        ),
         ProjectCancelled(RoundKey, ProjectKey),
         ProjectFundsWithdrawn(T::AccountId, ProjectKey, BalanceOf<T>, CurrencyId),
+        VestingPeriodUpdated(ProjectKey, Option<T::BlockNumber>),
+        /// A withdrawal started vesting, it is fully vested at the given block.
+        ProjectFundsVesting(T::AccountId, ProjectKey, BalanceOf<T>, CurrencyId, T::BlockNumber),
+        VestedFundsClaimed(T::AccountId, ProjectKey, BalanceOf<T>, CurrencyId),
+        /// A contributor delegated its milestone votes, on every project when the project is `None`.
+        VotesDelegated(T::AccountId, Option<ProjectKey>, T::AccountId),
+        VoteDelegationRevoked(T::AccountId, Option<ProjectKey>),
+        /// The delegate voted on a milestone with the given contributions delegated to it.
+        DelegatedVotesCast(T::AccountId, ProjectKey, MilestoneKey, BalanceOf<T>),
         ProjectApproved(RoundKey, ProjectKey),
         RoundCancelled(RoundKey),
+        /// The projects of a round which has not started yet were changed.
+        RoundProjectsUpdated(RoundKey, Vec<ProjectKey>),
         VoteComplete(T::AccountId, ProjectKey, MilestoneKey, bool, T::BlockNumber),
+        /// Native funds were locked until the given block to back a conviction vote.
+        ConvictionLocked(T::AccountId, BalanceOf<T>, T::BlockNumber),
+        /// A conviction lock ended, the account stays locked for the given amount.
+        ConvictionLockReleased(T::AccountId, BalanceOf<T>),
+        /// A milestone vote was changed, or removed when `None`.
+        VoteChanged(T::AccountId, ProjectKey, MilestoneKey, Option<bool>, T::BlockNumber),
         MilestoneApproved(T::AccountId, ProjectKey, MilestoneKey, T::BlockNumber),
+        MilestoneRejected(T::AccountId, ProjectKey, MilestoneKey, T::BlockNumber),
+        /// The milestone missed its deadline, contributors can vote to extend it or to refund.
+        MilestoneOverdue(ProjectKey, MilestoneKey, T::BlockNumber),
+        OverdueMilestoneVoteComplete(T::AccountId, ProjectKey, MilestoneKey, bool, T::BlockNumber),
+        /// The contributors gave an overdue milestone until the given block.
+        MilestoneDeadlineExtended(ProjectKey, MilestoneKey, T::BlockNumber),
         WhitelistAdded(ProjectKey, T::BlockNumber),
         WhitelistRemoved(ProjectKey, T::BlockNumber),
         ProjectLockedFundsRefunded(ProjectKey, BalanceOf<T>),
+        /// Governance rejected the project as spam, its deposit was slashed.
+        ProjectRejected(ProjectKey, BalanceOf<T>),
+        /// The storage deposits of a project were returned to the initiator.
+        ProjectDepositsReleased(ProjectKey, BalanceOf<T>),
+        /// Funds the initiator did not withdraw before the withdrawal expiration were reclaimed.
+        ExpiredFundsReclaimed(ProjectKey, BalanceOf<T>, ExpiredFundsDestination),
+        MatchingPoolFunded(RoundKey, T::AccountId, BalanceOf<T>, CurrencyId),
+        /// The project received its quadratic share of the matching pool of the round.
+        MatchingFundsDistributed(RoundKey, ProjectKey, BalanceOf<T>),
+        /// No project of the round could be matched, the sponsors got their funds back.
+        MatchingPoolRefunded(RoundKey, BalanceOf<T>),
+        /// The matching pool of the round could not be distributed, it is retried in the next block.
+        MatchingPoolDistributionFailed(RoundKey, DispatchError),
+        /// A contribution was returned to its contributor because the round was cancelled.
+        ContributionRefunded(RoundKey, ProjectKey, T::AccountId, BalanceOf<T>),
     }
 
     // Errors inform users that something went wrong.
@@ -194,9 +754,13 @@ pub mod pallet {
         ProposalApproved,
         ParamLimitExceed,
         RoundStarted,
+        /// Rounds can only be cancelled until they end.
+        RoundEnded,
         RoundNotEnded,
         RoundNotProcessing,
         RoundCanceled,
+        /// A project can only be part of one round of each type at a time.
+        ProjectAlreadyInRound,
         /// Errors should have helpful documentation associated with them.
         StartBlockNumberTooSmall,
         VoteAlreadyExists,
@@ -206,10 +770,87 @@ pub mod pallet {
         KeyNotFound,
         /// The input vector must exceed length zero.
         LengthMustExceedZero,
+        /// Projects which already received contributions cannot be rejected.
+        ProjectHasContributions,
+        ProjectCancelled,
+        /// Projects cannot be changed once they are scheduled in a round.
+        ProjectApprovedForFunding,
+        /// Only the account the ownership was offered to can accept it.
+        NoPendingOwnershipTransfer,
+        NoConfidenceRoundAlreadyRaised,
+        NoActiveNoConfidenceRound,
+        /// Only projects which were funded can be refunded by a vote of no confidence.
+        ProjectNotFunded,
+        /// Milestone deadlines must be in the future.
+        MilestoneDeadlineInPast,
+        /// Only unapproved milestones which missed their deadline can be voted on.
+        MilestoneNotOverdue,
+        /// Milestones must be submitted with evidence of the deliverable.
+        EvidenceIsMandatory,
+        MilestoneDoesNotExist,
+        MilestoneAlreadyApproved,
+        /// The latest submission of the milestone is still being voted on.
+        MilestoneVotingInProgress,
+        /// The latest submission of the milestone is no longer being voted on.
+        MilestoneVotingNotActive,
+        /// Rejected milestones can only be resubmitted once the cooldown is over.
+        MilestoneResubmissionTooEarly,
+        MaxMilestoneResubmissionsReached,
+        /// Matching pools can only be added to contribution rounds.
+        NotAContributionRound,
+        /// A matching pool only holds the currency it was first funded with.
+        MatchingPoolCurrencyMismatch,
+        /// Contributions cannot be withdrawn once the project reached its funding threshold.
+        FundingThresholdMet,
+        /// More than the contribution was asked back.
+        WithdrawalExceedsContribution,
+        /// The project did not raise enough to reach its soft cap.
+        FundingSoftCapNotMet,
+        /// The contribution would take the project beyond its hard cap.
+        HardCapExceeded,
+        /// The hard cap of a project cannot be lower than its required funds.
+        HardCapBelowRequiredFunds,
+        NoVestedFundsToClaim,
+        CannotDelegateToSelf,
+        NoVoteDelegation,
+        TooManyDelegators,
+        TooManyContributors,
+        TooManyTeamMembers,
+        ContributionsNotReserved,
+        ProjectCanStillBeFunded,
+        /// Only milestone votes which were cast can be changed.
+        VoteDoesNotExist,
+        VoteUnchanged,
+        /// Conviction only multiplies the voter's own contribution.
+        ConvictionRequiresContribution,
+        /// The native funds backing a conviction vote must be free.
+        InsufficientBalanceForConviction,
+        /// Conviction votes lock native funds, so they are only accepted on projects funded in the native currency.
+        ConvictionRequiresNativeCurrency,
+        TooManyConvictionLocks,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            Self::finalise_ended_milestone_votes(n)
+                .saturating_add(Self::reclaim_expired_withdrawals(n))
+                .saturating_add(Self::check_overdue_milestones(n))
+                .saturating_add(Self::distribute_ended_matching_pools(n))
+                .saturating_add(Self::release_expired_conviction_locks(n))
+                .saturating_add(Self::expire_ended_no_confidence_votes(n))
+        }
+
+        fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            Self::process_round_refunds(remaining_weight)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migration::v1::migrate::<T>()
+                .saturating_add(migration::v2::migrate::<T>())
+                .saturating_add(migration::v3::migrate::<T>())
+        }
+    }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
     // These functions materialize as "extrinsics", which are often compared to transactions.
@@ -243,6 +884,32 @@ pub mod pallet {
         }
 
 
+        /// Step 1.5 (INITATOR)
+        /// Update a project which is not part of a round yet
+        #[pallet::weight(<T as Config>::WeightInfo::update_project())]
+        pub fn update_project(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            name: BoundedStringField,
+            logo: BoundedStringField,
+            description: BoundedDescriptionField,
+            website: BoundedDescriptionField,
+            proposed_milestones: BoundedProposedMilestones,
+            required_funds: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_update_project(
+                who,
+                project_key,
+                name,
+                logo,
+                description,
+                website,
+                proposed_milestones,
+                required_funds,
+            )
+        }
+
         /// Step 1.5 (INITATOR)
         /// Add whitelist to a project
         #[pallet::weight(<T as Config>::WeightInfo::create_project())]
@@ -252,7 +919,11 @@ pub mod pallet {
             whitelist_spots: BoundedWhitelistSpots<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            Self::ensure_initator(who, project_key)?;
+            let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+            ensure!(
+                Self::has_project_role(&who, project_key, &project, ProjectRole::Edit),
+                Error::<T>::UserIsNotInitator
+            );
             let mut project_whitelist_spots: Vec<Whitelist<AccountIdOf<T>, BalanceOf<T>>> =
                 Vec::new();
 
@@ -263,6 +934,22 @@ pub mod pallet {
             }
 
             project_whitelist_spots.extend(whitelist_spots);
+
+            // Top up the deposit for the new whitelist spots
+            let whitelist_deposit = Self::storage_deposit(
+                project_whitelist_spots.len() as u32,
+                project_whitelist_spots.encoded_size(),
+            );
+            let reserved_deposit = WhitelistDeposits::<T>::get(project_key);
+            if whitelist_deposit > reserved_deposit {
+                T::MultiCurrency::reserve(
+                    CurrencyId::Native,
+                    &project.initiator,
+                    whitelist_deposit - reserved_deposit,
+                )?;
+                WhitelistDeposits::<T>::insert(project_key, whitelist_deposit);
+            }
+
             <WhitelistSpots<T>>::insert(project_key, project_whitelist_spots);
             let now = <frame_system::Pallet<T>>::block_number();
             Self::deposit_event(Event::WhitelistAdded(project_key, now));
@@ -277,56 +964,247 @@ pub mod pallet {
             project_key: ProjectKey,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            Self::ensure_initator(who, project_key)?;
+            let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+            ensure!(
+                Self::has_project_role(&who, project_key, &project, ProjectRole::Edit),
+                Error::<T>::UserIsNotInitator
+            );
             <WhitelistSpots<T>>::remove(project_key);
+            T::MultiCurrency::unreserve(
+                CurrencyId::Native,
+                &project.initiator,
+                WhitelistDeposits::<T>::take(project_key),
+            );
             let now = <frame_system::Pallet<T>>::block_number();
             Self::deposit_event(Event::WhitelistRemoved(project_key, now));
             Ok(().into())
         }
 
-        /// Step 2 (ADMIN)
-        /// Schedule a round
-        /// proposal_keys: the proposals were selected for this round
-        #[pallet::weight(<T as Config>::WeightInfo::schedule_round(MaxProposalCountPerRound::<T>::get()))]
-        pub fn schedule_round(
+        /// Ad Hoc Step (INITATOR)
+        /// Offer the ownership of a project to another account
+        #[pallet::weight(<T as Config>::WeightInfo::manage_project())]
+        pub fn propose_ownership_transfer(
             origin: OriginFor<T>,
-            start: T::BlockNumber,
-            end: T::BlockNumber,
-            project_keys: BoundedProjectKeys,
-            round_type: RoundType
+            project_key: ProjectKey,
+            new_owner: T::AccountId,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
-            Self::new_round(start, end, project_keys, round_type)
+            let who = ensure_signed(origin)?;
+            Self::ensure_initator(who, project_key)?;
+            PendingOwnershipTransfers::<T>::insert(project_key, &new_owner);
+            Self::deposit_event(Event::OwnershipTransferProposed(project_key, new_owner));
+            Ok(().into())
         }
 
-        /// Step 2.5 (ADMIN)
-        /// Cancel a round
-        /// This round must have not started yet
-        #[pallet::weight(<T as Config>::WeightInfo::cancel_round())]
+        /// Ad Hoc Step (NEW INITATOR)
+        /// Accept the ownership of a project, the team and beneficiary of the previous owner are removed
+        #[pallet::weight(<T as Config>::WeightInfo::accept_ownership_transfer(T::MaxTeamMembersPerProject::get()))]
+        pub fn accept_ownership_transfer(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_accept_ownership_transfer(who, project_key)
+        }
+
+        /// Ad Hoc Step (INITATOR)
+        /// Add, change or remove (with no roles) a member of the project team
+        #[pallet::weight(<T as Config>::WeightInfo::manage_project())]
+        pub fn set_team_member(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            member: T::AccountId,
+            roles: BoundedProjectRoles,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_initator(who, project_key)?;
+            let mut roles = roles.into_inner();
+            roles.sort();
+            roles.dedup();
+            let is_member = ProjectTeam::<T>::contains_key(project_key, &member);
+            if roles.is_empty() {
+                if is_member {
+                    ProjectTeam::<T>::remove(project_key, &member);
+                    ProjectTeamSize::<T>::mutate(project_key, |size| *size -= 1);
+                }
+            } else {
+                if !is_member {
+                    ensure!(
+                        Self::project_team_size(project_key) < T::MaxTeamMembersPerProject::get(),
+                        Error::<T>::TooManyTeamMembers
+                    );
+                    ProjectTeamSize::<T>::mutate(project_key, |size| *size += 1);
+                }
+                ProjectTeam::<T>::insert(project_key, &member, roles.clone());
+            }
+            Self::deposit_event(Event::TeamMemberUpdated(project_key, member, roles));
+            Ok(().into())
+        }
+
+        /// Ad Hoc Step (INITATOR)
+        /// Send withdrawals to another account, or back to the initiator with `None`
+        #[pallet::weight(<T as Config>::WeightInfo::manage_project())]
+        pub fn set_beneficiary(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            beneficiary: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_initator(who, project_key)?;
+            match &beneficiary {
+                Some(beneficiary) => ProjectBeneficiaries::<T>::insert(project_key, beneficiary),
+                None => ProjectBeneficiaries::<T>::remove(project_key),
+            }
+            Self::deposit_event(Event::BeneficiaryUpdated(project_key, beneficiary));
+            Ok(().into())
+        }
+
+        /// Ad Hoc Step (INITATOR)
+        /// Reserve contributions on the contributors' accounts until the project is funded
+        /// This can only be changed before the project receives contributions
+        #[pallet::weight(<T as Config>::WeightInfo::manage_project())]
+        pub fn set_reserve_contributions(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            reserve: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_initator(who, project_key)?;
+            let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+            ensure!(!project.funding_threshold_met, Error::<T>::FundingThresholdMet);
+            ensure!(
+                ProjectContributionsTotal::<T>::get(project_key) == (0_u32).into(),
+                Error::<T>::ProjectHasContributions
+            );
+            ReserveContributions::<T>::insert(project_key, reserve);
+            Self::deposit_event(Event::ContributionModeUpdated(project_key, reserve));
+            Ok(().into())
+        }
+
+        /// Ad Hoc Step (ANYONE)
+        /// Return the reserved contributions of a project whose contribution round ended without funding it
+        #[pallet::weight(<T as Config>::WeightInfo::release_reserved_contributions(T::MaxContributorsPerProject::get()))]
+        pub fn release_reserved_contributions(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::do_release_reserved_contributions(project_key)
+        }
+
+        /// Ad Hoc Step (INITATOR)
+        /// Let withdrawals vest over `vesting_period` blocks, or pay them out at once with `None`
+        /// This can only be changed before the project is funded
+        #[pallet::weight(<T as Config>::WeightInfo::manage_project())]
+        pub fn set_vesting_period(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            vesting_period: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_initator(who, project_key)?;
+            let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+            ensure!(!project.funding_threshold_met, Error::<T>::FundingThresholdMet);
+            match vesting_period {
+                Some(vesting_period) if vesting_period > (0_u32).into() => {
+                    ProjectVestingPeriods::<T>::insert(project_key, vesting_period)
+                }
+                _ => ProjectVestingPeriods::<T>::remove(project_key),
+            }
+            Self::deposit_event(Event::VestingPeriodUpdated(
+                project_key,
+                Self::project_vesting_periods(project_key),
+            ));
+            Ok(().into())
+        }
+
+        /// Ad Hoc Step (INITATOR)
+        /// Set the soft cap, hard cap and overfunding policy of a project
+        /// This can only be changed before the project receives contributions
+        #[pallet::weight(<T as Config>::WeightInfo::manage_project())]
+        pub fn set_funding_caps(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            funding_caps: FundingCaps<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_initator(who, project_key)?;
+            let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+            ensure!(!project.funding_threshold_met, Error::<T>::FundingThresholdMet);
+            ensure!(
+                ProjectContributionsTotal::<T>::get(project_key) == (0_u32).into(),
+                Error::<T>::ProjectHasContributions
+            );
+            if let Some(hard_cap) = funding_caps.hard_cap {
+                ensure!(
+                    hard_cap >= project.required_funds,
+                    Error::<T>::HardCapBelowRequiredFunds
+                );
+            }
+            ProjectFundingCaps::<T>::insert(project_key, funding_caps.clone());
+            Self::deposit_event(Event::FundingCapsUpdated(project_key, funding_caps));
+            Ok(().into())
+        }
+
+        /// Step 2 (ADMIN)
+        /// Schedule a round
+        /// proposal_keys: the proposals were selected for this round
+        #[pallet::weight(<T as Config>::WeightInfo::schedule_round(MaxProposalCountPerRound::<T>::get()))]
+        pub fn schedule_round(
+            origin: OriginFor<T>,
+            start: T::BlockNumber,
+            end: T::BlockNumber,
+            project_keys: BoundedProjectKeys,
+            round_type: RoundType
+        ) -> DispatchResultWithPostInfo {
+            T::RoundOrigin::ensure_origin(origin)?;
+            Self::new_round(start, end, project_keys, round_type)
+        }
+
+        /// Step 2.5 (ADMIN)
+        /// Cancel a round
+        /// This round must not have ended yet, what it received is refunded in on_idle
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_round(MaxProposalCountPerRound::<T>::get()))]
         pub fn cancel_round(
             origin: OriginFor<T>,
             round_key: RoundKey,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::RoundOrigin::ensure_origin(origin)?;
             let now = <frame_system::Pallet<T>>::block_number();
-            let count = RoundCount::<T>::get();
             let mut round = <Rounds<T>>::get(round_key).ok_or(Error::<T>::NoActiveRound)?;
 
-            // Ensure current round is not started
-            ensure!(round.start > now, Error::<T>::RoundStarted);
+            // Ensure current round has not ended
+            ensure!(round.end >= now, Error::<T>::RoundEnded);
             // This round cannot be cancelled
             ensure!(!round.is_canceled, Error::<T>::RoundCanceled);
 
             round.is_canceled = true;
-            <Rounds<T>>::insert(round_key, Some(round));
 
-            // TODO loop through projects and refund contributers
+            // Queue the projects of this round so their contributions are refunded in on_idle
+            for project_key in round.project_keys.iter() {
+                Self::unindex_project_round(*project_key, round.round_type, round_key);
+                RoundRefundQueue::<T>::insert((round_key, *project_key), ());
+            }
+            <Rounds<T>>::insert(round_key, Some(round));
 
-            Self::deposit_event(Event::RoundCancelled(count - 1));
+            Self::deposit_event(Event::RoundCancelled(round_key));
 
             Ok(().into())
         }
 
+        /// Step 2.5 (ADMIN)
+        /// Add projects to or remove projects from a round
+        /// This round must have not started yet
+        #[pallet::weight(<T as Config>::WeightInfo::update_round_projects(MaxProposalCountPerRound::<T>::get()))]
+        pub fn update_round_projects(
+            origin: OriginFor<T>,
+            round_key: RoundKey,
+            projects_to_add: BoundedProjectKeys,
+            projects_to_remove: BoundedProjectKeys,
+        ) -> DispatchResultWithPostInfo {
+            T::RoundOrigin::ensure_origin(origin)?;
+            Self::do_update_round_projects(round_key, projects_to_add, projects_to_remove)
+        }
+
         /// Step 3 (CONTRIBUTOR/FUNDER)
         /// Contribute to a proposal
         #[pallet::weight(<T as Config>::WeightInfo::contribute())]
@@ -340,33 +1218,150 @@ pub mod pallet {
             Self::new_contribution(who, project_key, value)
         }
 
+        /// Step 3.5 (CONTRIBUTOR/FUNDER)
+        /// Take back part or all of a contribution while the round is open
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_contribution())]
+        #[transactional]
+        pub fn withdraw_contribution(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_withdraw_contribution(who, project_key, value)
+        }
+
+        /// Step 3 (SPONSOR)
+        /// Add funds to the quadratic matching pool of a contribution round
+        #[pallet::weight(<T as Config>::WeightInfo::fund_matching_pool())]
+        pub fn fund_matching_pool(
+            origin: OriginFor<T>,
+            round_key: RoundKey,
+            currency_id: CurrencyId,
+            value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_fund_matching_pool(who, round_key, currency_id, value)
+        }
+
+        /// Step 3 (ADMIN)
+        /// Add treasury funds to the quadratic matching pool of a contribution round
+        #[pallet::weight(<T as Config>::WeightInfo::fund_matching_pool())]
+        pub fn fund_matching_pool_from_treasury(
+            origin: OriginFor<T>,
+            round_key: RoundKey,
+            currency_id: CurrencyId,
+            value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::do_fund_matching_pool(T::TreasuryAccount::get(), round_key, currency_id, value)
+        }
+
         /// Step 4 (ADMIN)
         /// Approve project
         /// If the project is approved, the project initator can withdraw funds for approved milestones
-        #[pallet::weight(<T as Config>::WeightInfo::approve())]
+        #[pallet::weight(<T as Config>::WeightInfo::approve(T::MaxContributorsPerProject::get()))]
+        #[transactional]
         pub fn approve(
             origin: OriginFor<T>,
             project_key: ProjectKey,
             milestone_keys: Option<BoundedMilestoneKeys>,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             Self::do_approve(project_key, milestone_keys)
         }
 
         /// Step 5 (INITATOR)
-        #[pallet::weight(<T as Config>::WeightInfo::submit_milestone())]
+        /// evidence: a link to the deliverable, e.g. an IPFS CID or a document root hash
+        #[pallet::weight(<T as Config>::WeightInfo::submit_milestone(T::MaxContributorsPerProject::get()))]
         pub fn submit_milestone(
             origin: OriginFor<T>,
             project_key: ProjectKey,
             milestone_key: MilestoneKey,
+            evidence: BoundedEvidenceField,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::new_milestone_submission(who, project_key, milestone_key, evidence)
+        }
+
+        /// Ad Hoc Step (CONTRIBUTOR/FUNDER)
+        /// Let `delegate` vote on milestones with your contribution, on a single project or on every project with `None`
+        #[pallet::weight(<T as Config>::WeightInfo::delegate_votes())]
+        pub fn delegate_votes(
+            origin: OriginFor<T>,
+            project_key: Option<ProjectKey>,
+            delegate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who != delegate, Error::<T>::CannotDelegateToSelf);
+            match project_key {
+                Some(project_key) => {
+                    ensure!(
+                        Projects::<T>::contains_key(project_key),
+                        Error::<T>::ProjectDoesNotExist
+                    );
+                    let previous_delegate = ProjectVoteDelegations::<T>::get(project_key, &who);
+                    if previous_delegate.as_ref() != Some(&delegate) {
+                        ProjectDelegators::<T>::try_mutate(project_key, &delegate, |delegators| {
+                            delegators.try_push(who.clone())
+                        })
+                        .map_err(|_| Error::<T>::TooManyDelegators)?;
+                        if let Some(previous_delegate) = previous_delegate {
+                            ProjectDelegators::<T>::mutate(project_key, &previous_delegate, |delegators| {
+                                delegators.retain(|delegator| delegator != &who)
+                            });
+                        }
+                    }
+                    ProjectVoteDelegations::<T>::insert(project_key, &who, &delegate);
+                }
+                None => {
+                    let previous_delegate = VoteDelegations::<T>::get(&who);
+                    if previous_delegate.as_ref() != Some(&delegate) {
+                        Delegators::<T>::try_mutate(&delegate, |delegators| delegators.try_push(who.clone()))
+                            .map_err(|_| Error::<T>::TooManyDelegators)?;
+                        if let Some(previous_delegate) = previous_delegate {
+                            Delegators::<T>::mutate(&previous_delegate, |delegators| {
+                                delegators.retain(|delegator| delegator != &who)
+                            });
+                        }
+                    }
+                    VoteDelegations::<T>::insert(&who, &delegate);
+                }
+            }
+            Self::deposit_event(Event::VotesDelegated(who, project_key, delegate));
+            Ok(().into())
+        }
+
+        /// Ad Hoc Step (CONTRIBUTOR/FUNDER)
+        /// Revoke a delegation, the votes the delegate already cast are kept
+        #[pallet::weight(<T as Config>::WeightInfo::delegate_votes())]
+        pub fn revoke_vote_delegation(
+            origin: OriginFor<T>,
+            project_key: Option<ProjectKey>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            Self::new_milestone_submission(who, project_key, milestone_key)
+            match project_key {
+                Some(project_key) => {
+                    let delegate = ProjectVoteDelegations::<T>::take(project_key, &who)
+                        .ok_or(Error::<T>::NoVoteDelegation)?;
+                    ProjectDelegators::<T>::mutate(project_key, &delegate, |delegators| {
+                        delegators.retain(|delegator| delegator != &who)
+                    });
+                }
+                None => {
+                    let delegate = VoteDelegations::<T>::take(&who).ok_or(Error::<T>::NoVoteDelegation)?;
+                    Delegators::<T>::mutate(&delegate, |delegators| {
+                        delegators.retain(|delegator| delegator != &who)
+                    });
+                }
+            }
+            Self::deposit_event(Event::VoteDelegationRevoked(who, project_key));
+            Ok(().into())
         }
 
         /// Step 6 (CONTRIBUTOR/FUNDER)
         /// Vote on a milestone
-        #[pallet::weight(<T as Config>::WeightInfo::contribute())]
+        #[pallet::weight(<T as Config>::WeightInfo::vote_on_milestone(Pallet::<T>::max_delegated_votes()))]
         pub fn vote_on_milestone(
             origin: OriginFor<T>,
             project_key: ProjectKey,
@@ -374,11 +1369,66 @@ pub mod pallet {
             approve_milestone: bool,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            Self::new_milestone_vote(who, project_key, milestone_key, approve_milestone)
+            Self::new_milestone_vote(
+                who,
+                project_key,
+                milestone_key,
+                approve_milestone,
+                Conviction::None,
+                (0_u32).into(),
+            )
+        }
+
+        /// Step 6 (CONTRIBUTOR/FUNDER)
+        /// Vote on a milestone, locking `locked_amount` native funds for the conviction's period
+        /// multiplies as much of your contribution
+        #[pallet::weight(<T as Config>::WeightInfo::vote_on_milestone_with_conviction(Pallet::<T>::max_delegated_votes()))]
+        pub fn vote_on_milestone_with_conviction(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+            approve_milestone: bool,
+            conviction: Conviction,
+            locked_amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::new_milestone_vote(
+                who,
+                project_key,
+                milestone_key,
+                approve_milestone,
+                conviction,
+                locked_amount,
+            )
+        }
+
+        /// Step 6.5 (CONTRIBUTOR/FUNDER)
+        /// Change a milestone vote while its voting round is open
+        #[pallet::weight(<T as Config>::WeightInfo::change_milestone_vote(Pallet::<T>::max_delegated_votes()))]
+        pub fn change_milestone_vote(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+            approve_milestone: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_change_milestone_vote(who, project_key, milestone_key, Some(approve_milestone))
+        }
+
+        /// Step 6.5 (CONTRIBUTOR/FUNDER)
+        /// Remove a milestone vote while its voting round is open
+        #[pallet::weight(<T as Config>::WeightInfo::change_milestone_vote(Pallet::<T>::max_delegated_votes()))]
+        pub fn remove_milestone_vote(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_change_milestone_vote(who, project_key, milestone_key, None)
         }
 
         /// Step 7 (INITATOR)
-        #[pallet::weight(<T as Config>::WeightInfo::submit_milestone())]
+        #[pallet::weight(<T as Config>::WeightInfo::finalise_milestone_voting(T::MaxContributorsPerProject::get()))]
         pub fn finalise_milestone_voting(
             origin: OriginFor<T>,
             project_key: ProjectKey,
@@ -388,6 +1438,46 @@ pub mod pallet {
             Self::do_finalise_milestone_voting(who, project_key, milestone_key)
         }
 
+        /// Ad Hoc Step (CONTRIBUTOR/FUNDER)
+        /// Raise a vote of no confidence in the project, voting in favour of a refund
+        #[pallet::weight(<T as Config>::WeightInfo::vote_of_no_confidence(T::MaxContributorsPerProject::get()))]
+        #[transactional]
+        pub fn raise_vote_of_no_confidence(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::new_no_confidence_round(who, project_key)
+        }
+
+        /// Ad Hoc Step (CONTRIBUTOR/FUNDER)
+        /// Vote in an open vote of no confidence, `is_yay` is in favour of a refund
+        #[pallet::weight(<T as Config>::WeightInfo::vote_of_no_confidence(T::MaxContributorsPerProject::get()))]
+        #[transactional]
+        pub fn vote_on_no_confidence_round(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            is_yay: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::new_no_confidence_vote(who, project_key, is_yay)
+        }
+
+        /// Ad Hoc Step (CONTRIBUTOR/FUNDER)
+        /// Vote on an overdue milestone, `refund` is in favour of a refund
+        /// and against it in favour of extending the deadline
+        #[pallet::weight(<T as Config>::WeightInfo::vote_on_overdue_milestone(T::MaxContributorsPerProject::get()))]
+        #[transactional]
+        pub fn vote_on_overdue_milestone(
+            origin: OriginFor<T>,
+            project_key: ProjectKey,
+            milestone_key: MilestoneKey,
+            refund: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::new_overdue_milestone_vote(who, project_key, milestone_key, refund)
+        }
+
         /// Step 8 (INITATOR)
         /// Withdraw
         #[pallet::weight(<T as Config>::WeightInfo::withdraw())]
@@ -396,16 +1486,24 @@ pub mod pallet {
             Self::new_withdrawal(who, project_key)
         }
 
+        /// Step 8.5 (INITATOR)
+        /// Claim the funds which vested since the last claim
+        #[pallet::weight(<T as Config>::WeightInfo::claim_vested_funds())]
+        pub fn claim_vested_funds(origin: OriginFor<T>, project_key: ProjectKey) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_claim_vested_funds(who, project_key)
+        }
+
         /// Set max proposal count per round
         #[pallet::weight(<T as Config>::WeightInfo::set_max_proposal_count_per_round(T::MaxProposalsPerRound::get()))]
         pub fn set_max_proposal_count_per_round(
             origin: OriginFor<T>,
             max_proposal_count_per_round: u32,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             ensure!(
                 max_proposal_count_per_round > 0
-                    || max_proposal_count_per_round <= T::MaxProposalsPerRound::get(),
+                    && max_proposal_count_per_round <= T::MaxProposalsPerRound::get(),
                 Error::<T>::ParamLimitExceed
             );
             MaxProposalCountPerRound::<T>::put(max_proposal_count_per_round);
@@ -420,7 +1518,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             new_milestone_voting_window: u32,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             ensure!(
                 new_milestone_voting_window > 0,
                 Error::<T>::ParamLimitExceed
@@ -430,6 +1528,46 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Set milestone voting quorum
+        #[pallet::weight(<T as Config>::WeightInfo::set_milestone_voting_quorum())]
+        pub fn set_milestone_voting_quorum(
+            origin: OriginFor<T>,
+            new_milestone_voting_quorum: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            MilestoneVotingQuorum::<T>::put(new_milestone_voting_quorum);
+
+            Ok(().into())
+        }
+
+        /// Set the share of the required funds projects must raise to be fundable once their round ended
+        #[pallet::weight(<T as Config>::WeightInfo::set_funding_soft_cap())]
+        pub fn set_funding_soft_cap(
+            origin: OriginFor<T>,
+            new_funding_soft_cap: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            FundingSoftCap::<T>::put(new_funding_soft_cap);
+
+            Ok(().into())
+        }
+
+        /// Set milestone approval threshold
+        #[pallet::weight(<T as Config>::WeightInfo::set_milestone_approval_threshold())]
+        pub fn set_milestone_approval_threshold(
+            origin: OriginFor<T>,
+            new_milestone_approval_threshold: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                new_milestone_approval_threshold < Perbill::one(),
+                Error::<T>::ParamLimitExceed
+            );
+            MilestoneApprovalThreshold::<T>::put(new_milestone_approval_threshold);
+
+            Ok(().into())
+        }
+
 
 
         /// Set withdrawal expiration
@@ -438,11 +1576,15 @@ pub mod pallet {
             origin: OriginFor<T>,
             withdrawal_expiration: T::BlockNumber,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             ensure!(
                 withdrawal_expiration > (0_u32).into(),
                 Error::<T>::InvalidParam
             );
+            ensure!(
+                withdrawal_expiration <= T::MaxWithdrawalExpiration::get(),
+                Error::<T>::WithdrawalExpirationExceed
+            );
             <WithdrawalExpiration<T>>::put(withdrawal_expiration);
 
             Ok(().into())
@@ -454,18 +1596,26 @@ pub mod pallet {
             origin: OriginFor<T>,
             is_identity_required: bool,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
             IsIdentityRequired::<T>::put(is_identity_required);
 
             Ok(().into())
         }
 
+        /// Ad Hoc Step (ADMIN)
+        /// Reject a spam project, its storage deposits are slashed to the treasury
+        #[pallet::weight(<T as Config>::WeightInfo::reject_project())]
+        pub fn reject_project(origin: OriginFor<T>, project_key: ProjectKey) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::do_reject_project(project_key)
+        }
+
         /// Ad Hoc Step (ADMIN)
         /// Refund
-        #[pallet::weight(<T as Config>::WeightInfo::refund())]
+        #[pallet::weight(<T as Config>::WeightInfo::refund(T::MaxContributorsPerProject::get()))]
         pub fn refund(origin: OriginFor<T>, project_key: ProjectKey) -> DispatchResultWithPostInfo {
             //ensure only admin can perform refund
-            ensure_root(origin)?;
+            T::RefundOrigin::ensure_origin(origin)?;
             Self::do_refund(project_key)
         }
     }
@@ -488,24 +1638,354 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Whether `who` is the initiator of the project or a team member with `role`.
+    pub fn has_project_role(
+        who: &T::AccountId,
+        project_key: ProjectKey,
+        project: &ProjectOf<T>,
+        role: ProjectRole,
+    ) -> bool {
+        project.initiator == *who
+            || Self::project_team(project_key, who).map_or(false, |roles| roles.contains(&role))
+    }
+
+    fn do_accept_ownership_transfer(
+        who: T::AccountId,
+        project_key: ProjectKey,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(
+            Self::pending_ownership_transfers(project_key).as_ref() == Some(&who),
+            Error::<T>::NoPendingOwnershipTransfer
+        );
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        let previous_owner = project.initiator.clone();
+
+        // The storage deposits move to the new owner
+        let deposit = ProjectDeposits::<T>::get(project_key)
+            .saturating_add(WhitelistDeposits::<T>::get(project_key));
+        T::MultiCurrency::repatriate_reserved(
+            CurrencyId::Native,
+            &previous_owner,
+            &who,
+            deposit,
+            BalanceStatus::Reserved,
+        )?;
+        PendingOwnershipTransfers::<T>::remove(project_key);
+
+        // The new owner chooses their own team and beneficiary
+        ProjectTeam::<T>::drain_prefix(project_key).for_each(drop);
+        ProjectTeamSize::<T>::remove(project_key);
+        ProjectBeneficiaries::<T>::remove(project_key);
+
+        let updated_project = Project {
+            name: project.name,
+            logo: project.logo,
+            description: project.description,
+            website: project.website,
+            milestones: project.milestones,
+            required_funds: project.required_funds,
+            currency_id: project.currency_id,
+            withdrawn_funds: project.withdrawn_funds,
+            initiator: who.clone(),
+            create_block_number: project.create_block_number,
+            approved_for_funding: project.approved_for_funding,
+            funding_threshold_met: project.funding_threshold_met,
+            cancelled: project.cancelled,
+        };
+        <Projects<T>>::insert(project_key, updated_project);
+        Self::deposit_event(Event::OwnershipTransferred(project_key, previous_owner, who));
+
+        Ok(().into())
+    }
+
     pub fn project_account_id(key: ProjectKey) -> T::AccountId {
         T::PalletId::get().into_sub_account_truncating(key)
     }
 
+    /// The account holding the matching pool of a round.
+    pub fn matching_pool_account_id(round_key: RoundKey) -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating((b"matching", round_key))
+    }
+
     pub fn get_project(project_key: u32) -> Result<Project<AccountIdOf<T>, BalanceOf<T>, T::BlockNumber>, Error<T>> {
         Self::projects(project_key).ok_or(Error::<T>::ProjectDoesNotExist)
     }
 
     pub fn get_total_project_contributions(project_key: u32) -> Result<BalanceOf<T>, Error<T>> {
-        let project = Self::projects(project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
-        // Calculate contribution amount
-        let mut total_contribution_amount: BalanceOf<T> = (0_u32).into();
-        for contribution in project.contributions.iter() {
-            let contribution_value = contribution.value;
-            total_contribution_amount += contribution_value;
-        }
-        Ok(total_contribution_amount)
-    }
+        ensure!(
+            Projects::<T>::contains_key(project_key),
+            Error::<T>::ProjectDoesNotExist
+        );
+        Ok(ProjectContributionsTotal::<T>::get(project_key))
+    }
+
+    /// Whether the contributions of a project are still reserved on the contributors' accounts.
+    fn holds_reserved_contributions(project_key: ProjectKey, project: &ProjectOf<T>) -> bool {
+        !project.funding_threshold_met && ReserveContributions::<T>::get(project_key)
+    }
+
+    /// The named reserve holding the contributions to a project, so that moving them never
+    /// touches funds the contributor reserved for anything else.
+    fn contribution_reserve_id(project_key: ProjectKey) -> [u8; 8] {
+        let mut reserve_id = [0u8; 8];
+        reserve_id[..4].copy_from_slice(&CONTRIBUTION_RESERVE_PREFIX);
+        reserve_id[4..].copy_from_slice(&project_key.to_le_bytes());
+        reserve_id
+    }
+
+    /// Return the reserved contributions of a project once its contribution round ended, or was
+    /// cancelled, without the project reaching its soft cap, or its required funds when no soft cap is set.
+    fn do_release_reserved_contributions(project_key: ProjectKey) -> DispatchResultWithPostInfo {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(
+            Self::holds_reserved_contributions(project_key, &project),
+            Error::<T>::ContributionsNotReserved
+        );
+        // Cancelled rounds are no longer indexed, leaving the project without an open round
+        if let Some(round_key) = Self::project_rounds(project_key, RoundType::ContributionRound) {
+            let round = Self::rounds(round_key).ok_or(Error::<T>::KeyNotFound)?;
+            ensure!(round.is_canceled || round.end < now, Error::<T>::RoundNotEnded);
+        }
+
+        let total_funds: BalanceOf<T> = Self::get_total_project_funds(project_key)?;
+        ensure!(
+            total_funds < Self::funding_target(project_key, &project),
+            Error::<T>::ProjectCanStillBeFunded
+        );
+
+        let reserve_id = Self::contribution_reserve_id(project_key);
+        let mut released_funds: BalanceOf<T> = (0_u32).into();
+        let contributions: Vec<(T::AccountId, BalanceOf<T>)> =
+            Contributions::<T>::iter_prefix(project_key).collect();
+        for (who, value) in contributions.into_iter() {
+            T::MultiCurrency::unreserve_named(&reserve_id, project.currency_id, &who, value);
+            Self::remove_contribution(project_key, &who);
+            released_funds += value;
+        }
+        ProjectContributionsTotal::<T>::remove(project_key);
+
+        Self::deposit_event(Event::ReservedContributionsReleased(project_key, released_funds));
+        Ok(().into())
+    }
+
+    /// The funds a project must raise in its contribution round to be funded: its soft cap,
+    /// or its required funds when no soft cap is set.
+    fn funding_target(project_key: ProjectKey, project: &ProjectOf<T>) -> BalanceOf<T> {
+        let soft_cap = Self::project_funding_caps(project_key)
+            .and_then(|caps| caps.soft_cap)
+            .unwrap_or_else(Self::funding_soft_cap);
+        if soft_cap.is_zero() {
+            project.required_funds
+        } else {
+            soft_cap.mul_ceil(project.required_funds)
+        }
+    }
+
+    /// Move the reserved contributions of a project to its account once it is funded.
+    ///
+    /// Contributions are lowered to what could actually be moved, in case the contributor's
+    /// reserve was slashed in the meantime.
+    fn transfer_reserved_contributions(
+        project_key: ProjectKey,
+        currency_id: CurrencyId,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let project_account = Self::project_account_id(project_key);
+        let reserve_id = Self::contribution_reserve_id(project_key);
+        let mut transferred_funds: BalanceOf<T> = (0_u32).into();
+        let contributions: Vec<(T::AccountId, BalanceOf<T>)> =
+            Contributions::<T>::iter_prefix(project_key).collect();
+        for (who, value) in contributions.into_iter() {
+            let missing = T::MultiCurrency::repatriate_reserved_named(
+                &reserve_id,
+                currency_id,
+                &who,
+                &project_account,
+                value,
+                BalanceStatus::Free,
+            )?;
+            if missing > (0_u32).into() {
+                let transferred = value.saturating_sub(missing);
+                ProjectContributionsTotal::<T>::mutate(project_key, |total| {
+                    *total = total.saturating_sub(missing)
+                });
+                if transferred == (0_u32).into() {
+                    Self::remove_contribution(project_key, &who);
+                } else {
+                    Contributions::<T>::insert(project_key, &who, transferred);
+                }
+            }
+            transferred_funds += value.saturating_sub(missing);
+        }
+        Self::deposit_event(Event::ReservedContributionsTransferred(project_key, transferred_funds));
+        Ok(transferred_funds)
+    }
+
+    /// Return the funds raised beyond `required_funds` to the contributors, in proportion to
+    /// their contributions. The rounding leftovers stay with the project.
+    fn return_overfunding(
+        project_key: ProjectKey,
+        currency_id: CurrencyId,
+        overfunding: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let total_contributions = ProjectContributionsTotal::<T>::get(project_key);
+        let overfunding = overfunding.min(total_contributions);
+        if overfunding == (0_u32).into() {
+            return Ok(overfunding);
+        }
+
+        let share = Perbill::from_rational(overfunding, total_contributions);
+        let project_account = Self::project_account_id(project_key);
+        let mut returned_funds: BalanceOf<T> = (0_u32).into();
+        let contributions: Vec<(T::AccountId, BalanceOf<T>)> =
+            Contributions::<T>::iter_prefix(project_key).collect();
+        for (who, value) in contributions.into_iter() {
+            let refund_amount = share.mul_floor(value);
+            if refund_amount == (0_u32).into() {
+                continue;
+            }
+            T::MultiCurrency::transfer(currency_id, &project_account, &who, refund_amount)?;
+
+            let remaining_value = value - refund_amount;
+            if remaining_value == (0_u32).into() {
+                Self::remove_contribution(project_key, &who);
+            } else {
+                Contributions::<T>::insert(project_key, &who, remaining_value);
+            }
+            returned_funds += refund_amount;
+        }
+        ProjectContributionsTotal::<T>::mutate(project_key, |total| {
+            *total = total.saturating_sub(returned_funds)
+        });
+
+        Self::deposit_event(Event::OverfundingReturned(project_key, returned_funds));
+        Ok(returned_funds)
+    }
+
+    /// Remove the contribution of `who` and stop counting it as a contributor of the project.
+    fn remove_contribution(project_key: ProjectKey, who: &T::AccountId) {
+        if Contributions::<T>::take(project_key, who).is_some() {
+            ProjectContributorCount::<T>::mutate(project_key, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    /// The contributions of a project together with the matching funds it received.
+    pub fn get_total_project_funds(project_key: u32) -> Result<BalanceOf<T>, Error<T>> {
+        let total_contribution_amount = Self::get_total_project_contributions(project_key)?;
+        Ok(total_contribution_amount.saturating_add(ProjectMatchedFunds::<T>::get(project_key)))
+    }
+
+    /// Refund the contributions made to the rounds queued by `cancel_round`.
+    ///
+    /// Only what was contributed during the cancelled round is refunded, projects that were
+    /// funded, cancelled or are being voted on in the meantime are skipped.
+    /// Contributions are refunded one by one until the queue is empty or the next refund
+    /// would exceed `remaining_weight`, whatever is left is picked up in a later block.
+    pub fn process_round_refunds(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let refund_weight = <T as Config>::WeightInfo::refund_contribution();
+        let mut consumed_weight: Weight = db_weight.reads(1);
+        if remaining_weight < consumed_weight.saturating_add(refund_weight) {
+            return 0;
+        }
+
+        while let Some((round_key, project_key)) = RoundRefundQueue::<T>::iter_keys().next() {
+            consumed_weight = consumed_weight.saturating_add(db_weight.reads_writes(3, 1));
+            let project = match Projects::<T>::get(project_key) {
+                Some(project)
+                    if !project.funding_threshold_met
+                        && !project.cancelled
+                        && Self::processing_round(project_key, RoundType::VotingRound)
+                            .is_none() =>
+                {
+                    project
+                }
+                _ => {
+                    RoundContributions::<T>::drain_prefix((round_key, project_key)).for_each(drop);
+                    RoundRefundQueue::<T>::remove((round_key, project_key));
+                    continue;
+                }
+            };
+            let is_reserved = Self::holds_reserved_contributions(project_key, &project);
+
+            let max_refunds = remaining_weight.saturating_sub(consumed_weight) / refund_weight.max(1);
+            if max_refunds == 0 {
+                break;
+            }
+
+            let mut processed_refunds: Weight = 0;
+            let mut refunded_funds: BalanceOf<T> = (0_u32).into();
+            let mut failed_refunds: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+            for (who, round_value) in RoundContributions::<T>::drain_prefix((round_key, project_key))
+                .take(max_refunds as usize)
+            {
+                processed_refunds += 1;
+                let existing_contribution_value: BalanceOf<T> =
+                    Self::contributions(project_key, &who).unwrap_or_else(|| (0_u32).into());
+                let value = round_value.min(existing_contribution_value);
+                if value == (0_u32).into() {
+                    continue;
+                }
+                // Reserved contributions never left the contributor's account
+                let refund = if is_reserved {
+                    T::MultiCurrency::unreserve_named(
+                        &Self::contribution_reserve_id(project_key),
+                        project.currency_id,
+                        &who,
+                        value,
+                    );
+                    Ok(())
+                } else {
+                    T::MultiCurrency::transfer(
+                        project.currency_id,
+                        &Self::project_account_id(project_key),
+                        &who,
+                        value,
+                    )
+                };
+                match refund {
+                    Ok(()) => {
+                        let new_contribution_value = existing_contribution_value - value;
+                        if new_contribution_value == (0_u32).into() {
+                            Self::remove_contribution(project_key, &who);
+                        } else {
+                            <Contributions<T>>::insert(project_key, &who, new_contribution_value);
+                        }
+                        refunded_funds += value;
+                        Self::deposit_event(Event::ContributionRefunded(
+                            round_key,
+                            project_key,
+                            who,
+                            value,
+                        ));
+                    }
+                    // Keep the contribution so it is not lost, it can still be refunded manually
+                    Err(_) => failed_refunds.push((who, round_value)),
+                }
+            }
+            consumed_weight =
+                consumed_weight.saturating_add(refund_weight.saturating_mul(processed_refunds));
+
+            ProjectContributionsTotal::<T>::mutate(project_key, |total| {
+                *total = total.saturating_sub(refunded_funds)
+            });
+            let all_refunds_failed =
+                processed_refunds > 0 && failed_refunds.len() as Weight == processed_refunds;
+
+            // Continue with this project in a later block unless it is drained or cannot be refunded
+            if processed_refunds == max_refunds && !all_refunds_failed {
+                for (who, round_value) in failed_refunds {
+                    RoundContributions::<T>::insert((round_key, project_key), who, round_value);
+                }
+                break;
+            }
+            // Failed refunds keep their contribution, it can still be refunded manually
+            RoundContributions::<T>::drain_prefix((round_key, project_key)).for_each(drop);
+            RoundRefundQueue::<T>::remove((round_key, project_key));
+        }
+
+        consumed_weight
+    }
 
     fn new_project(
         who: T::AccountId,
@@ -535,40 +2015,13 @@ impl<T: Config> Pallet<T> {
         }
 
         // Validation
-        ensure!(!name.is_empty(), Error::<T>::ProjectNameIsMandatory);
-        ensure!(!logo.is_empty(), Error::<T>::LogoIsMandatory);
-        ensure!(
-            !description.is_empty(),
-            Error::<T>::ProjectDescriptionIsMandatory
-        );
-        ensure!(!website.is_empty(), Error::<T>::WebsiteURLIsMandatory);
-
-        let mut total_percentage = 0;
-        for milestone in proposed_milestones.iter() {
-            total_percentage += milestone.percentage_to_unlock;
-        }
-        ensure!(
-            total_percentage == 100,
-            Error::<T>::MilestonesTotalPercentageMustEqual100
-        );
+        Self::validate_project_fields(&name, &logo, &description, &website, &proposed_milestones)?;
 
         let project_key = ProjectCount::<T>::get();
         let next_project_key = project_key.checked_add(1).ok_or(Error::<T>::Overflow)?;
 
-        let mut milestones = Vec::new();
-        let mut milestone_key: u32 = 0;
-
         // Fill in the proposals structure in advance
-        for milestone in proposed_milestones {
-            milestones.push(Milestone {
-                project_key,
-                milestone_key,
-                name: milestone.name.to_vec(),
-                percentage_to_unlock: milestone.percentage_to_unlock,
-                is_approved: false,
-            });
-            milestone_key = milestone_key.checked_add(1).ok_or(Error::<T>::Overflow)?;
-        }
+        let milestones = Self::build_milestones(project_key, &proposed_milestones)?;
 
         // Create a proposal
         let project = Project {
@@ -577,7 +2030,6 @@ impl<T: Config> Pallet<T> {
             description: description.to_vec(),
             website: website.to_vec(),
             milestones,
-            contributions: Vec::new(),
             required_funds,
             currency_id,
             withdrawn_funds: (0_u32).into(),
@@ -588,8 +2040,14 @@ impl<T: Config> Pallet<T> {
             cancelled: false,
         };
 
+        // Reserve the deposit for storing the project
+        let project_deposit = Self::storage_deposit(1, project.encoded_size());
+        T::MultiCurrency::reserve(CurrencyId::Native, &who, project_deposit)?;
+        ProjectDeposits::<T>::insert(project_key, project_deposit);
+
         // Add proposal to list
         <Projects<T>>::insert(project_key, project);
+        Self::set_milestone_deadlines(project_key, &proposed_milestones);
         ProjectCount::<T>::put(next_project_key);
 
         Self::deposit_event(Event::ProjectCreated(
@@ -603,6 +2061,148 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    fn do_update_project(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        name: BoundedStringField,
+        logo: BoundedStringField,
+        description: BoundedDescriptionField,
+        website: BoundedDescriptionField,
+        proposed_milestones: BoundedProposedMilestones,
+        required_funds: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(
+            Self::has_project_role(&who, project_key, &project, ProjectRole::Edit),
+            Error::<T>::UserIsNotInitator
+        );
+        ensure!(!project.cancelled, Error::<T>::ProjectCancelled);
+        // Projects can only be edited until they are scheduled in a round
+        ensure!(
+            !project.approved_for_funding,
+            Error::<T>::ProjectApprovedForFunding
+        );
+
+        // Validation
+        Self::validate_project_fields(&name, &logo, &description, &website, &proposed_milestones)?;
+        if let Some(hard_cap) = Self::project_funding_caps(project_key).and_then(|caps| caps.hard_cap) {
+            ensure!(hard_cap >= required_funds, Error::<T>::HardCapBelowRequiredFunds);
+        }
+        let milestones = Self::build_milestones(project_key, &proposed_milestones)?;
+
+        let updated_project = Project {
+            name: name.to_vec(),
+            logo: logo.to_vec(),
+            description: description.to_vec(),
+            website: website.to_vec(),
+            milestones,
+            required_funds,
+            currency_id: project.currency_id,
+            withdrawn_funds: project.withdrawn_funds,
+            initiator: project.initiator,
+            create_block_number: project.create_block_number,
+            approved_for_funding: project.approved_for_funding,
+            funding_threshold_met: project.funding_threshold_met,
+            cancelled: project.cancelled,
+        };
+
+        // The deposit follows the new size of the project
+        let project_deposit = Self::storage_deposit(1, updated_project.encoded_size());
+        let reserved_deposit = ProjectDeposits::<T>::get(project_key);
+        if project_deposit > reserved_deposit {
+            T::MultiCurrency::reserve(
+                CurrencyId::Native,
+                &updated_project.initiator,
+                project_deposit - reserved_deposit,
+            )?;
+        } else {
+            T::MultiCurrency::unreserve(
+                CurrencyId::Native,
+                &updated_project.initiator,
+                reserved_deposit - project_deposit,
+            );
+        }
+        ProjectDeposits::<T>::insert(project_key, project_deposit);
+
+        // The deadlines of the previous milestones no longer apply
+        for milestone in project.milestones.iter() {
+            MilestoneDeadlines::<T>::remove((project_key, milestone.milestone_key));
+        }
+        Self::set_milestone_deadlines(project_key, &proposed_milestones);
+
+        <Projects<T>>::insert(project_key, updated_project);
+        Self::deposit_event(Event::ProjectUpdated(who, project_key, required_funds));
+
+        Ok(().into())
+    }
+
+    fn validate_project_fields(
+        name: &BoundedStringField,
+        logo: &BoundedStringField,
+        description: &BoundedDescriptionField,
+        website: &BoundedDescriptionField,
+        proposed_milestones: &BoundedProposedMilestones,
+    ) -> Result<(), Error<T>> {
+        ensure!(!name.is_empty(), Error::<T>::ProjectNameIsMandatory);
+        ensure!(!logo.is_empty(), Error::<T>::LogoIsMandatory);
+        ensure!(
+            !description.is_empty(),
+            Error::<T>::ProjectDescriptionIsMandatory
+        );
+        ensure!(!website.is_empty(), Error::<T>::WebsiteURLIsMandatory);
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        let mut total_percentage = 0;
+        for milestone in proposed_milestones.iter() {
+            total_percentage += milestone.percentage_to_unlock;
+            if let Some(deadline) = milestone.deadline {
+                ensure!(
+                    T::BlockNumber::from(deadline) > now,
+                    Error::<T>::MilestoneDeadlineInPast
+                );
+            }
+        }
+        ensure!(
+            total_percentage == 100,
+            Error::<T>::MilestonesTotalPercentageMustEqual100
+        );
+
+        Ok(())
+    }
+
+    fn build_milestones(
+        project_key: ProjectKey,
+        proposed_milestones: &BoundedProposedMilestones,
+    ) -> Result<Vec<Milestone>, Error<T>> {
+        let mut milestones = Vec::new();
+        let mut milestone_key: u32 = 0;
+
+        for milestone in proposed_milestones.iter() {
+            milestones.push(Milestone {
+                project_key,
+                milestone_key,
+                name: milestone.name.to_vec(),
+                percentage_to_unlock: milestone.percentage_to_unlock,
+                is_approved: false,
+            });
+            milestone_key = milestone_key.checked_add(1).ok_or(Error::<T>::Overflow)?;
+        }
+
+        Ok(milestones)
+    }
+
+    /// Store the deadlines of the proposed milestones, in the order of their milestone keys.
+    fn set_milestone_deadlines(project_key: ProjectKey, proposed_milestones: &BoundedProposedMilestones) {
+        for (milestone_key, milestone) in proposed_milestones.iter().enumerate() {
+            if let Some(deadline) = milestone.deadline {
+                let deadline: T::BlockNumber = deadline.into();
+                let vote_lookup_key = (project_key, milestone_key as MilestoneKey);
+                MilestoneDeadlines::<T>::insert(vote_lookup_key, deadline);
+                MilestonesDueAt::<T>::append(deadline, vote_lookup_key);
+            }
+        }
+    }
+
     fn new_round(
         start: T::BlockNumber,
         end: T::BlockNumber,
@@ -611,7 +2211,10 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResultWithPostInfo {
         let now = <frame_system::Pallet<T>>::block_number();
         // The number of items cannot exceed the maximum
-        // ensure!(project_keyes.len() as u32 <= MaxProposalCountPerRound::<T>::get(), Error::<T>::ProposalAmountExceed);
+        ensure!(
+            project_keys.len() as u32 <= MaxProposalCountPerRound::<T>::get(),
+            Error::<T>::ProposalAmountExceed
+        );
         // The end block must be greater than the start block
         ensure!(end > start, Error::<T>::EndTooEarly);
         // Both the starting block number and the ending block number must be greater than the current number of blocks
@@ -640,6 +2243,10 @@ impl<T: Config> Pallet<T> {
             round_type.clone(),
         );
 
+        for project_key in project_keys.iter() {
+            Self::ensure_not_in_open_round(*project_key, round_type, key)?;
+        }
+
         // Add proposal round to list
         <Rounds<T>>::insert(key, Some(round));
 
@@ -654,7 +2261,6 @@ impl<T: Config> Pallet<T> {
                 description: project.description,
                 website: project.website,
                 milestones: project.milestones,
-                contributions: project.contributions.clone(),
                 required_funds: project.required_funds,
                 currency_id: project.currency_id,
                 withdrawn_funds: project.withdrawn_funds,
@@ -667,6 +2273,7 @@ impl<T: Config> Pallet<T> {
 
             // Add proposal to list
             <Projects<T>>::insert(project_key, updated_project);
+            Self::index_project_round(*project_key, round_type, key);
         }
 
         match round_type.clone() {
@@ -678,86 +2285,187 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
-    pub fn new_contribution(
-        who: T::AccountId,
-        project_key: ProjectKey,
-        value: BalanceOf<T>,
+    fn do_update_round_projects(
+        round_key: RoundKey,
+        projects_to_add: BoundedProjectKeys,
+        projects_to_remove: BoundedProjectKeys,
     ) -> DispatchResultWithPostInfo {
-        ensure!(value > (0_u32).into(), Error::<T>::InvalidParam);
-        let project_count = ProjectCount::<T>::get();
-        ensure!(project_key < project_count, Error::<T>::InvalidParam);
         let now = <frame_system::Pallet<T>>::block_number();
+        let mut round = <Rounds<T>>::get(round_key).ok_or(Error::<T>::NoActiveRound)?;
 
-        // round list must be not none
-        let round_key = RoundCount::<T>::get();
-        ensure!(round_key > 0, Error::<T>::NoActiveRound);
-        let mut project_exists_in_round = false;
-        // Find processing round
-        let mut processing_round: Option<RoundOf<T>> = None;
-        for i in (0..round_key).rev() {
-
-            let round = Self::rounds(i).ok_or(Error::<T>::KeyNotFound)?;
-            if !round.is_canceled && round.start < now && round.end > now {
-                // Find proposal by key
-                for current_project_key in round.project_keys.iter() {
-                    if current_project_key == &project_key {
-                        project_exists_in_round = true;
-                        processing_round = Some(round.clone());
-                        break;
-                    }
-                }
+        // Ensure current round is not started
+        ensure!(round.start > now, Error::<T>::RoundStarted);
+        ensure!(!round.is_canceled, Error::<T>::RoundCanceled);
+
+        let project_count = ProjectCount::<T>::get();
+        for project_key in projects_to_add.iter() {
+            ensure!(project_key < &project_count, Error::<T>::ProjectDoesNotExist);
+            Self::ensure_not_in_open_round(*project_key, round.round_type, round_key)?;
+            if !round.project_keys.contains(project_key) {
+                round.project_keys.push(*project_key);
             }
         }
-        let round = processing_round.ok_or(Error::<T>::RoundNotProcessing)?;
-        ensure!(project_exists_in_round, Error::<T>::ProjectNotInRound);
-        let mut project =
-            Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
-        let mut max_cap = (0_u32).into();
-        let mut new_contribution_value: BalanceOf<T> = value;
-        let mut found_contribution: Option<&ContributionOf<T>> = None;
-        let mut existing_contribution_index = 0;
-
-        for (index, contribution) in project.contributions.iter().enumerate() {
-            if contribution.account_id == who {
-                new_contribution_value += contribution.value;
-                found_contribution = Some(contribution);
-                existing_contribution_index = index;
-                break;
-            }
+        for project_key in projects_to_remove.iter() {
+            ensure!(round.project_keys.contains(project_key), Error::<T>::ProjectNotInRound);
         }
+        round.project_keys.retain(|project_key| !projects_to_remove.contains(project_key));
 
-        // Find whitelist if exists
-        if WhitelistSpots::<T>::contains_key(project_key) {
-            let mut contributer_is_whitelisted = false;
-            let whitelist_spots = Self::whitelist_spots(project_key).ok_or(Error::<T>::KeyNotFound)?;
-            for whitelist_spot in whitelist_spots.clone().into_iter() {
-                if whitelist_spot.who == who {
-                    contributer_is_whitelisted = true;
-                    max_cap = whitelist_spot.max_cap;
-                    break;
-                }
-            }
-
-            ensure!(
-                contributer_is_whitelisted,
-                Error::<T>::OnlyWhitelistedAccountsCanContribute
-            );
-
-            ensure!(
-                max_cap == (0_u32).into() || max_cap >= new_contribution_value,
-                Error::<T>::ContributionMustBeLowerThanMaxCap
-            );
-        }
+        ensure!(round.project_keys.len() > 0usize, Error::<T>::LengthMustExceedZero);
+        ensure!(
+            round.project_keys.len() as u32 <= MaxProposalCountPerRound::<T>::get(),
+            Error::<T>::ProposalAmountExceed
+        );
 
-        // Transfer contribute to proposal account
-        T::MultiCurrency::transfer(
-            project.currency_id,
-            &who,
-            &Self::project_account_id(project_key),
-            value,
-        )?;
+        for project_key in projects_to_add.iter() {
+            if projects_to_remove.contains(project_key) {
+                continue;
+            }
+            let project =
+                Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
 
-        <Rounds<T>>::insert(round_key - 1, Some(round));
+            let updated_project = Project {
+                name: project.name,
+                logo: project.logo,
+                description: project.description,
+                website: project.website,
+                milestones: project.milestones,
+                required_funds: project.required_funds,
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                initiator: project.initiator,
+                create_block_number: project.create_block_number,
+                approved_for_funding: true,
+                funding_threshold_met: project.funding_threshold_met,
+                cancelled: project.cancelled,
+            };
+            <Projects<T>>::insert(project_key, updated_project);
+            Self::index_project_round(*project_key, round.round_type, round_key);
+        }
+
+        // Removed projects which are not scheduled in another open round can be edited again
+        for project_key in projects_to_remove.iter() {
+            if round.project_keys.contains(project_key) {
+                continue;
+            }
+            Self::unindex_project_round(*project_key, round.round_type, round_key);
+            let is_scheduled = [RoundType::ContributionRound, RoundType::VotingRound]
+                .iter()
+                .filter_map(|round_type| Self::project_rounds(project_key, round_type))
+                .filter(|other_round_key| other_round_key != &round_key)
+                .filter_map(Self::rounds)
+                .any(|other_round| {
+                    !other_round.is_canceled
+                        && other_round.end >= now
+                        && other_round.project_keys.contains(project_key)
+                });
+            let project =
+                Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+            let has_contributions =
+                ProjectContributionsTotal::<T>::get(project_key) > (0_u32).into();
+            if is_scheduled || has_contributions || project.funding_threshold_met {
+                continue;
+            }
+
+            let updated_project = Project {
+                name: project.name,
+                logo: project.logo,
+                description: project.description,
+                website: project.website,
+                milestones: project.milestones,
+                required_funds: project.required_funds,
+                currency_id: project.currency_id,
+                withdrawn_funds: project.withdrawn_funds,
+                initiator: project.initiator,
+                create_block_number: project.create_block_number,
+                approved_for_funding: false,
+                funding_threshold_met: project.funding_threshold_met,
+                cancelled: project.cancelled,
+            };
+            <Projects<T>>::insert(project_key, updated_project);
+        }
+
+        let project_keys = round.project_keys.clone();
+        <Rounds<T>>::insert(round_key, Some(round));
+        Self::deposit_event(Event::RoundProjectsUpdated(round_key, project_keys));
+
+        Ok(().into())
+    }
+
+    pub fn new_contribution(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        value: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(value > (0_u32).into(), Error::<T>::InvalidParam);
+        let project_count = ProjectCount::<T>::get();
+        ensure!(project_key < project_count, Error::<T>::InvalidParam);
+        let now = <frame_system::Pallet<T>>::block_number();
+
+        // round list must be not none
+        ensure!(RoundCount::<T>::get() > 0, Error::<T>::NoActiveRound);
+        // Find processing round
+        let (round_key, _) = Self::processing_round(project_key, RoundType::ContributionRound)
+            .ok_or(Error::<T>::RoundNotProcessing)?;
+        let project =
+            Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        let mut max_cap = (0_u32).into();
+        let existing_contribution_value: BalanceOf<T> =
+            Self::contributions(project_key, &who).unwrap_or_else(|| (0_u32).into());
+        let new_contribution_value: BalanceOf<T> = existing_contribution_value + value;
+
+        // Find whitelist if exists
+        if WhitelistSpots::<T>::contains_key(project_key) {
+            let mut contributer_is_whitelisted = false;
+            let whitelist_spots = Self::whitelist_spots(project_key).ok_or(Error::<T>::KeyNotFound)?;
+            for whitelist_spot in whitelist_spots.clone().into_iter() {
+                if whitelist_spot.who == who {
+                    contributer_is_whitelisted = true;
+                    max_cap = whitelist_spot.max_cap;
+                    break;
+                }
+            }
+
+            ensure!(
+                contributer_is_whitelisted,
+                Error::<T>::OnlyWhitelistedAccountsCanContribute
+            );
+
+            ensure!(
+                max_cap == (0_u32).into() || max_cap >= new_contribution_value,
+                Error::<T>::ContributionMustBeLowerThanMaxCap
+            );
+        }
+
+        let is_new_contributor = existing_contribution_value == (0_u32).into();
+        ensure!(
+            !is_new_contributor
+                || Self::project_contributor_count(project_key) < T::MaxContributorsPerProject::get(),
+            Error::<T>::TooManyContributors
+        );
+
+        if let Some(hard_cap) = Self::project_funding_caps(project_key).and_then(|caps| caps.hard_cap) {
+            ensure!(
+                ProjectContributionsTotal::<T>::get(project_key).saturating_add(value) <= hard_cap,
+                Error::<T>::HardCapExceeded
+            );
+        }
+
+        // Transfer contribute to proposal account, or keep it reserved until the project is funded
+        if Self::holds_reserved_contributions(project_key, &project) {
+            T::MultiCurrency::reserve_named(
+                &Self::contribution_reserve_id(project_key),
+                project.currency_id,
+                &who,
+                value,
+            )?;
+        } else {
+            T::MultiCurrency::transfer(
+                project.currency_id,
+                &who,
+                &Self::project_account_id(project_key),
+                value,
+            )?;
+        }
 
         Self::deposit_event(Event::ContributeSucceeded(
             who.clone(),
@@ -767,47 +2475,219 @@ impl<T: Config> Pallet<T> {
             now,
         ));
 
-        // Find previous contribution by account_id
-        // If you have contributed before, then add to that contribution. Otherwise join the list.
-        match found_contribution.clone() {
-            Some(_contribution) => {
-                // project.contributions.remove(&contribution);
-                project.contributions.remove(existing_contribution_index);
-                project.contributions.push(ContributionOf::<T> {
-                    account_id: who.clone(),
-                    value: new_contribution_value,
-                });
+        // If you have contributed before, then add to that contribution.
+        <Contributions<T>>::insert(project_key, &who, new_contribution_value);
+        if is_new_contributor {
+            ProjectContributorCount::<T>::mutate(project_key, |count| *count += 1);
+        }
+        RoundContributions::<T>::mutate((round_key, project_key), &who, |round_value| {
+            *round_value += value
+        });
+        ProjectContributionsTotal::<T>::mutate(project_key, |total| *total += value);
+
+        Ok(().into())
+    }
+
+    fn do_withdraw_contribution(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        value: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(value > (0_u32).into(), Error::<T>::InvalidParam);
+        let now = <frame_system::Pallet<T>>::block_number();
+        let (round_key, _) = Self::processing_round(project_key, RoundType::ContributionRound)
+            .ok_or(Error::<T>::RoundNotProcessing)?;
+        let project =
+            Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(!project.funding_threshold_met, Error::<T>::FundingThresholdMet);
+
+        let existing_contribution_value: BalanceOf<T> =
+            Self::contributions(project_key, &who).ok_or(Error::<T>::KeyNotFound)?;
+        ensure!(
+            value <= existing_contribution_value,
+            Error::<T>::WithdrawalExceedsContribution
+        );
+        let new_contribution_value = existing_contribution_value - value;
+
+        if Self::holds_reserved_contributions(project_key, &project) {
+            T::MultiCurrency::unreserve_named(
+                &Self::contribution_reserve_id(project_key),
+                project.currency_id,
+                &who,
+                value,
+            );
+        } else {
+            T::MultiCurrency::transfer(
+                project.currency_id,
+                &Self::project_account_id(project_key),
+                &who,
+                value,
+            )?;
+        }
+
+        // Whitelist caps are checked against the remaining contribution
+        if new_contribution_value == (0_u32).into() {
+            Self::remove_contribution(project_key, &who);
+        } else {
+            <Contributions<T>>::insert(project_key, &who, new_contribution_value);
+        }
+        RoundContributions::<T>::mutate_exists((round_key, project_key), &who, |round_value| {
+            let remaining = round_value.unwrap_or_default().saturating_sub(value);
+            *round_value = if remaining == (0_u32).into() { None } else { Some(remaining) };
+        });
+        ProjectContributionsTotal::<T>::mutate(project_key, |total| *total -= value);
+
+        Self::deposit_event(Event::ContributionWithdrawn(
+            who,
+            project_key,
+            value,
+            project.currency_id,
+            now,
+        ));
+
+        Ok(().into())
+    }
+
+    fn do_fund_matching_pool(
+        who: T::AccountId,
+        round_key: RoundKey,
+        currency_id: CurrencyId,
+        value: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(value > (0_u32).into(), Error::<T>::InvalidParam);
+        let now = <frame_system::Pallet<T>>::block_number();
+        let round = Self::rounds(round_key).ok_or(Error::<T>::NoActiveRound)?;
+        ensure!(
+            round.round_type == RoundType::ContributionRound,
+            Error::<T>::NotAContributionRound
+        );
+        ensure!(!round.is_canceled, Error::<T>::RoundCanceled);
+        ensure!(round.end > now, Error::<T>::RoundNotProcessing);
+
+        let pool = match Self::matching_pools(round_key) {
+            Some(pool) => {
+                ensure!(pool.currency_id == currency_id, Error::<T>::MatchingPoolCurrencyMismatch);
+                pool
             }
             None => {
-                project.contributions.push(ContributionOf::<T> {
-                    account_id: who.clone(),
-                    value,
+                MatchingPoolsEndingAt::<T>::append(round.end, round_key);
+                MatchingPool {
+                    currency_id,
+                    total: (0_u32).into(),
+                }
+            }
+        };
+
+        T::MultiCurrency::transfer(
+            currency_id,
+            &who,
+            &Self::matching_pool_account_id(round_key),
+            value,
+        )?;
+        MatchingPoolSponsors::<T>::mutate(round_key, &who, |sponsored| *sponsored += value);
+        MatchingPools::<T>::insert(
+            round_key,
+            MatchingPool {
+                currency_id,
+                total: pool.total + value,
+            },
+        );
+        Self::deposit_event(Event::MatchingPoolFunded(round_key, who, value, currency_id));
+
+        Ok(().into())
+    }
+
+    /// Distribute the matching pools of the rounds ending at this block.
+    /// Pools which could not be distributed are retried in the next block.
+    fn distribute_ended_matching_pools(now: T::BlockNumber) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let round_keys = MatchingPoolsEndingAt::<T>::take(now);
+
+        for round_key in round_keys.into_iter() {
+            weight = weight.saturating_add(<T as Config>::WeightInfo::distribute_matching_pool(
+                MaxProposalCountPerRound::<T>::get(),
+                T::MaxContributorsPerProject::get(),
+            ));
+            if let Err(error) = Self::distribute_matching_pool(round_key) {
+                // The pool is left untouched, retry rather than leaving the funds stuck
+                if MatchingPools::<T>::contains_key(round_key) {
+                    MatchingPoolsEndingAt::<T>::append(now + 1_u32.into(), round_key);
+                }
+                Self::deposit_event(Event::MatchingPoolDistributionFailed(round_key, error));
+            }
+        }
+
+        weight
+    }
+
+    /// Split the matching pool of a round between its funded projects by the square of the sum
+    /// of the square roots of the contributions made in the round.
+    /// Projects in another currency or short of their funding target are left out.
+    #[transactional]
+    fn distribute_matching_pool(round_key: RoundKey) -> DispatchResult {
+        let pool = MatchingPools::<T>::take(round_key).ok_or(Error::<T>::KeyNotFound)?;
+        let round = Self::rounds(round_key).ok_or(Error::<T>::KeyNotFound)?;
+        let pool_account = Self::matching_pool_account_id(round_key);
+
+        let mut project_weights: Vec<(ProjectKey, BalanceOf<T>)> = Vec::new();
+        let mut total_weight: BalanceOf<T> = (0_u32).into();
+        if !round.is_canceled {
+            for project_key in round.project_keys.iter() {
+                let is_matched = Self::projects(project_key).map_or(false, |project| {
+                    let is_funded = project.funding_threshold_met
+                        || Self::get_total_project_funds(*project_key).map_or(false, |total_funds| {
+                            total_funds >= Self::funding_target(*project_key, &project)
+                        });
+                    !project.cancelled && project.currency_id == pool.currency_id && is_funded
                 });
+                let sqrt_sum = RoundContributions::<T>::iter_prefix_values((round_key, *project_key))
+                    .fold((0_u32).into(), |sqrt_sum: BalanceOf<T>, value| {
+                        sqrt_sum.saturating_add(value.integer_sqrt())
+                    });
+                let weight = sqrt_sum.saturating_mul(sqrt_sum);
+                if is_matched && weight > (0_u32).into() {
+                    project_weights.push((*project_key, weight));
+                    total_weight = total_weight.saturating_add(weight);
+                }
             }
         }
 
-        // Update project withdrawn funds
-        let updated_project = Project {
-            name: project.name,
-            logo: project.logo,
-            description: project.description,
-            website: project.website,
-            milestones: project.milestones,
-            contributions: project.contributions.clone(),
-            required_funds: project.required_funds,
-            currency_id: project.currency_id,
-            withdrawn_funds: project.withdrawn_funds,
-            initiator: project.initiator,
-            create_block_number: project.create_block_number,
-            approved_for_funding: project.approved_for_funding,
-            funding_threshold_met: project.funding_threshold_met,
-            cancelled: project.cancelled,
-        };
+        if total_weight == (0_u32).into() {
+            for (sponsor, value) in MatchingPoolSponsors::<T>::drain_prefix(round_key) {
+                T::MultiCurrency::transfer(pool.currency_id, &pool_account, &sponsor, value)?;
+            }
+            Self::deposit_event(Event::MatchingPoolRefunded(round_key, pool.total));
+            return Ok(());
+        }
 
-        // Add proposal to list
-        <Projects<T>>::insert(project_key, updated_project);
+        let mut distributed_funds: BalanceOf<T> = (0_u32).into();
+        for (project_key, weight) in project_weights.into_iter() {
+            let matched_funds: BalanceOf<T> =
+                Perbill::from_rational(weight, total_weight) * pool.total;
+            T::MultiCurrency::transfer(
+                pool.currency_id,
+                &pool_account,
+                &Self::project_account_id(project_key),
+                matched_funds,
+            )?;
+            ProjectMatchedFunds::<T>::mutate(project_key, |funds| *funds += matched_funds);
+            distributed_funds += matched_funds;
+            Self::deposit_event(Event::MatchingFundsDistributed(round_key, project_key, matched_funds));
+        }
 
-        Ok(().into())
+        // What is left over from rounding goes to the treasury
+        let remaining_funds = pool.total.saturating_sub(distributed_funds);
+        if remaining_funds > (0_u32).into() {
+            T::MultiCurrency::transfer(
+                pool.currency_id,
+                &pool_account,
+                &T::TreasuryAccount::get(),
+                remaining_funds,
+            )?;
+        }
+        MatchingPoolSponsors::<T>::drain_prefix(round_key).for_each(drop);
+
+        Ok(())
     }
 
     pub fn do_approve(
@@ -815,41 +2695,48 @@ impl<T: Config> Pallet<T> {
         milestone_keys: Option<BoundedMilestoneKeys>,
     ) -> DispatchResultWithPostInfo {
         let round_key = RoundCount::<T>::get();
-        // Find processing round
-        let mut latest_round: Option<RoundOf<T>> = None;
-        let mut project_exists_in_round = false;
-
-        for i in (0..round_key).rev() {
-            // Get the current round and check that both the key exists and the value under the key is some.
-            let current_round = Self::rounds(i).ok_or(Error::<T>::KeyNotFound)?;
-
-            if !current_round.is_canceled && current_round.project_keys.contains(&project_key) {
-                latest_round = Some(current_round);
-                project_exists_in_round = true;
-                break;
-            }
-        }
-
-        let round = latest_round.ok_or(Error::<T>::NoActiveRound)?;
+        // Find the latest round of the project
+        let latest_round_key = [RoundType::ContributionRound, RoundType::VotingRound]
+            .iter()
+            .filter_map(|round_type| Self::project_rounds(project_key, round_type))
+            .max()
+            .ok_or(Error::<T>::NoActiveRound)?;
+        let round = Self::rounds(latest_round_key).ok_or(Error::<T>::KeyNotFound)?;
         ensure!(!round.is_canceled, Error::<T>::RoundCanceled);
+        ensure!(round.project_keys.contains(&project_key), Error::<T>::ProjectNotInRound);
 
         // The round must have ended
         let now = <frame_system::Pallet<T>>::block_number();
 
-        ensure!(project_exists_in_round, Error::<T>::ProjectNotInRound);
-
         let mut project =
             Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
-        let total_contribution_amount: BalanceOf<T> =
-            Self::get_total_project_contributions(project_key)?;
+        let total_funds: BalanceOf<T> = Self::get_total_project_funds(project_key)?;
 
 
-        let funds_matched = total_contribution_amount >= project.required_funds;
+        let funding_caps = Self::project_funding_caps(project_key).unwrap_or_default();
+        let funds_matched = total_funds >= project.required_funds;
         if !funds_matched {
             // If the funds have not been matched then check if the round is over
             ensure!(round.end < now, Error::<T>::RoundNotEnded);
 
-            // Once the round ends, check for the funding threshold met. (set threshold for 75%)
+            // Once the round ends, the project is fundable when it reached its soft cap
+            if !project.funding_threshold_met {
+                ensure!(
+                    total_funds >= Self::funding_target(project_key, &project),
+                    Error::<T>::FundingSoftCapNotMet
+                );
+            }
+        }
+
+        if Self::holds_reserved_contributions(project_key, &project) {
+            Self::transfer_reserved_contributions(project_key, project.currency_id)?;
+        }
+        if !project.funding_threshold_met && funding_caps.return_overfunding && total_funds > project.required_funds {
+            Self::return_overfunding(
+                project_key,
+                project.currency_id,
+                total_funds - project.required_funds,
+            )?;
         }
 
         let mut milestones = project.milestones.clone();
@@ -879,13 +2766,33 @@ impl<T: Config> Pallet<T> {
                         }
 
                         Self::deposit_event(Event::MilestoneApproved(project.initiator.clone(), project_key, *key, now));
+                        Self::close_milestone_submission(
+                            vote_lookup_key,
+                            MilestoneSubmissionStatus::Approved(now),
+                            updated_vote.clone(),
+                        );
                         <MilestoneVotes<T>>::insert(vote_lookup_key, updated_vote);
+                        OverdueMilestoneVotes::<T>::remove(vote_lookup_key);
                     }
                 }
                 milestones.push(milestone.clone());
             }
         }
-        <Rounds<T>>::insert(round_key, Some(round));
+
+        // The project is completed once every milestone is approved
+        let is_completed = milestones.iter().all(|milestone| milestone.is_approved);
+        let initiator = project.initiator.clone();
+
+        // Milestones which fell due before the project was funded are overdue right away
+        let overdue_milestones: Vec<(MilestoneKey, T::BlockNumber)> = milestones
+            .iter()
+            .filter(|milestone| !milestone.is_approved)
+            .filter_map(|milestone| {
+                Self::milestone_deadlines((project_key, milestone.milestone_key))
+                    .filter(|deadline| deadline < &now)
+                    .map(|deadline| (milestone.milestone_key, deadline))
+            })
+            .collect();
 
         // Update project milestones
         let updated_project = Project {
@@ -893,11 +2800,957 @@ impl<T: Config> Pallet<T> {
             logo: project.logo,
             description: project.description,
             website: project.website,
-            milestones,
-            contributions: project.contributions,
+            milestones,
+            required_funds: project.required_funds,
+            currency_id: project.currency_id,
+            withdrawn_funds: project.withdrawn_funds,
+            initiator: project.initiator,
+            create_block_number: project.create_block_number,
+            approved_for_funding: project.approved_for_funding,
+            funding_threshold_met: project.funding_threshold_met,
+            cancelled: project.cancelled,
+        };
+        // Add proposal to list
+        <Projects<T>>::insert(project_key, updated_project);
+        if is_completed {
+            Self::release_deposits(project_key, &initiator);
+        }
+        for (milestone_key, deadline) in overdue_milestones.into_iter() {
+            Self::open_overdue_milestone_vote(project_key, milestone_key, deadline);
+        }
+        if milestone_keys.is_some() {
+            Self::refresh_withdrawal_expiry(project_key, now);
+        }
+        Self::deposit_event(Event::ProjectApproved(round_key, project_key));
+        Ok(().into())
+    }
+
+    pub fn new_milestone_submission(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        evidence: BoundedEvidenceField,
+    ) -> DispatchResultWithPostInfo {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(!evidence.is_empty(), Error::<T>::EvidenceIsMandatory);
+
+        ensure!(
+            Self::has_project_role(&who, project_key, &project, ProjectRole::SubmitMilestones),
+            Error::<T>::UserIsNotInitator
+        );
+        ensure!(
+            project.funding_threshold_met,
+            Error::<T>::OnlyApprovedProjectsCanSubmitMilestones
+        );
+        ensure!(
+            project.milestones.iter().any(|milestone| milestone.milestone_key == milestone_key),
+            Error::<T>::MilestoneDoesNotExist
+        );
+
+        let vote_lookup_key = (project_key, milestone_key);
+        let mut submissions = Self::milestone_submissions(vote_lookup_key);
+        if let Some(latest_submission) = submissions.last() {
+            match latest_submission.status {
+                MilestoneSubmissionStatus::Voting => {
+                    return Err(Error::<T>::MilestoneVotingInProgress.into())
+                }
+                MilestoneSubmissionStatus::Approved(_) => {
+                    return Err(Error::<T>::MilestoneAlreadyApproved.into())
+                }
+                MilestoneSubmissionStatus::Rejected(rejected_at) => ensure!(
+                    now >= rejected_at.saturating_add(T::MilestoneResubmissionCooldown::get()),
+                    Error::<T>::MilestoneResubmissionTooEarly
+                ),
+            }
+            // The first submission does not count as a resubmission
+            ensure!(
+                submissions.len() as u32 <= T::MaxMilestoneResubmissions::get(),
+                Error::<T>::MaxMilestoneResubmissionsReached
+            );
+        }
+
+        let end = now + MilestoneVotingWindow::<T>::get().into();
+        let key = RoundCount::<T>::get();
+        let round = RoundOf::<T>::new(now, end, vec![project_key], RoundType::VotingRound);
+        let next_key = key.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+        // Snapshot the voting weights so that later changes to the contributions do not move the tally,
+        // the snapshot is bounded by `MaxContributorsPerProject`
+        let mut total_vote_weight: BalanceOf<T> = (0_u32).into();
+        let mut voters: u32 = 0;
+        for (contributor, value) in Contributions::<T>::iter_prefix(project_key) {
+            MilestoneVoteSnapshots::<T>::insert(key, &contributor, value);
+            total_vote_weight += value;
+            voters += 1;
+        }
+        MilestoneVoteSnapshotTotals::<T>::insert(key, (total_vote_weight, voters));
+
+        let vote = Vote {
+            yay: (0_u32).into(),
+            nay: (0_u32).into(),
+            is_approved: false,
+        };
+        // Every submission starts a fresh vote
+        <MilestoneVotes<T>>::insert(vote_lookup_key, vote.clone());
+        submissions.push(MilestoneSubmission {
+            round_key: key,
+            submitted_at: now,
+            status: MilestoneSubmissionStatus::Voting,
+            votes: vote,
+        });
+        MilestoneSubmissions::<T>::insert(vote_lookup_key, submissions);
+        // Earlier evidence is kept when a milestone is resubmitted
+        MilestoneEvidence::<T>::append(vote_lookup_key, evidence.to_vec());
+        Self::deposit_event(Event::MilestoneSubmitted(
+            who,
+            project_key,
+            milestone_key,
+            evidence.to_vec(),
+        ));
+        // Add proposal round to list
+        <Rounds<T>>::insert(key, Some(round));
+        Self::index_project_round(project_key, RoundType::VotingRound, key);
+        MilestoneVotingRounds::<T>::insert(vote_lookup_key, key);
+        MilestoneVotesEndingAt::<T>::append(end, vote_lookup_key);
+        RoundCount::<T>::put(next_key);
+        Self::deposit_event(Event::VotingRoundCreated(key, vec![project_key]));
+        Ok(().into())
+    }
+
+    pub fn new_milestone_vote(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        approve_milestone: bool,
+        conviction: Conviction,
+        locked_amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let project_count = ProjectCount::<T>::get();
+        ensure!(project_key < project_count, Error::<T>::InvalidParam);
+        let now = <frame_system::Pallet<T>>::block_number();
+
+        // round list must be not none
+        let round_key = RoundCount::<T>::get();
+        ensure!(round_key > 0, Error::<T>::NoActiveRound);
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+
+        let latest_round_key = Self::milestone_voting_round_key(project_key, milestone_key, now)?;
+        Self::ensure_milestone_voting_active(project_key, milestone_key)?;
+
+        let vote_lookup_key = (who.clone(), project_key, milestone_key, latest_round_key);
+        let vote_exists = UserVotes::<T>::contains_key(vote_lookup_key.clone());
+        ensure!(!vote_exists, Error::<T>::VoteAlreadyExists);
+
+        // Add up the contributions delegated to the voter which did not vote in this round yet,
+        // a delegation on the project itself takes precedence over one on every project
+        let project_delegators = ProjectDelegators::<T>::get(project_key, &who);
+        let global_delegators = Delegators::<T>::get(&who)
+            .into_iter()
+            .filter(|delegator| !ProjectVoteDelegations::<T>::contains_key(project_key, delegator));
+        let mut delegators: Vec<T::AccountId> = Vec::new();
+        let mut delegated_amount: BalanceOf<T> = (0_u32).into();
+        for delegator in project_delegators.into_iter().chain(global_delegators) {
+            if UserVotes::<T>::contains_key((delegator.clone(), project_key, milestone_key, latest_round_key)) {
+                continue;
+            }
+            if let Some(value) = Self::milestone_vote_weight(project_key, latest_round_key, &delegator) {
+                delegated_amount += value;
+                delegators.push(delegator);
+            }
+        }
+
+        let own_contribution = Self::milestone_vote_weight(project_key, latest_round_key, &who);
+        ensure!(
+            own_contribution.is_some() || !delegators.is_empty(),
+            Error::<T>::OnlyContributorsCanVote
+        );
+        let own_weight: BalanceOf<T> = own_contribution.unwrap_or_else(|| (0_u32).into());
+
+        // Conviction multiplies as much of the voter's own weight as the native funds it locks
+        let mut contribution_amount: BalanceOf<T> = own_weight + delegated_amount;
+        if conviction != Conviction::None {
+            ensure!(
+                project.currency_id == CurrencyId::Native,
+                Error::<T>::ConvictionRequiresNativeCurrency
+            );
+            ensure!(
+                own_weight > (0_u32).into() && locked_amount > (0_u32).into(),
+                Error::<T>::ConvictionRequiresContribution
+            );
+            Self::lock_for_conviction(&who, locked_amount, conviction, now)?;
+            UserVoteConvictions::<T>::insert(vote_lookup_key.clone(), (conviction, locked_amount));
+            contribution_amount = Self::conviction_vote_weight(own_weight, conviction, locked_amount)
+                + delegated_amount;
+        }
+
+        <UserVotes<T>>::insert(vote_lookup_key, approve_milestone);
+        for delegator in delegators.iter() {
+            let delegator_vote_key = (delegator.clone(), project_key, milestone_key, latest_round_key);
+            <UserVotes<T>>::insert(delegator_vote_key.clone(), approve_milestone);
+            VoteCastBy::<T>::insert(delegator_vote_key, &who);
+        }
+
+        let user_milestone_vote = Self::milestone_votes((project_key, milestone_key)).ok_or(Error::<T>::KeyNotFound)?;
+
+        if approve_milestone {
+            let updated_vote = Vote {
+                yay: user_milestone_vote.yay + contribution_amount,
+                nay: user_milestone_vote.nay,
+                is_approved: user_milestone_vote.is_approved,
+            };
+            <MilestoneVotes<T>>::insert((project_key, milestone_key), updated_vote)
+        } else {
+            let updated_vote = Vote {
+                yay: user_milestone_vote.yay,
+                nay: user_milestone_vote.nay + contribution_amount,
+                is_approved: user_milestone_vote.is_approved,
+            };
+            <MilestoneVotes<T>>::insert((project_key, milestone_key), updated_vote)
+        }
+
+        if !delegators.is_empty() {
+            DelegatedVotes::<T>::insert((who.clone(), project_key, milestone_key, latest_round_key), delegators);
+            Self::deposit_event(Event::DelegatedVotesCast(
+                who.clone(),
+                project_key,
+                milestone_key,
+                delegated_amount,
+            ));
+        }
+
+        Self::deposit_event(Event::VoteComplete(
+            who,
+            project_key,
+            milestone_key,
+            approve_milestone,
+            now,
+        ));
+
+        Ok(().into())
+    }
+
+    /// The voting weight of `who` in a milestone voting round.
+    fn milestone_vote_weight(
+        project_key: ProjectKey,
+        round_key: RoundKey,
+        who: &T::AccountId,
+    ) -> Option<BalanceOf<T>> {
+        if MilestoneVoteSnapshotTotals::<T>::contains_key(round_key) {
+            Self::milestone_vote_snapshots(round_key, who)
+        } else {
+            Self::contributions(project_key, who)
+        }
+    }
+
+    /// The open voting round of the latest submission of a milestone.
+    fn milestone_voting_round_key(
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        now: T::BlockNumber,
+    ) -> Result<RoundKey, DispatchError> {
+        // Votes are cast in the voting round of the latest submission of the milestone
+        let round_key = match Self::milestone_voting_rounds((project_key, milestone_key)) {
+            Some(round_key) => {
+                let round = Self::rounds(round_key).ok_or(Error::<T>::RoundNotProcessing)?;
+                ensure!(
+                    !round.is_canceled && round.start < now && round.end > now,
+                    Error::<T>::RoundNotProcessing
+                );
+                round_key
+            }
+            None => {
+                Self::processing_round(project_key, RoundType::VotingRound)
+                    .ok_or(Error::<T>::RoundNotProcessing)?
+                    .0
+            }
+        };
+        Ok(round_key)
+    }
+
+    /// Move a milestone vote to the other side of the tally, or take it out when `new_vote` is `None`.
+    ///
+    /// The votes a delegate cast for its delegators follow the delegate, a delegator changing its
+    /// own vote takes its contribution out of the delegate's vote.
+    fn do_change_milestone_vote(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        new_vote: Option<bool>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(
+            Projects::<T>::contains_key(project_key),
+            Error::<T>::ProjectDoesNotExist
+        );
+        let now = <frame_system::Pallet<T>>::block_number();
+        let round_key = Self::milestone_voting_round_key(project_key, milestone_key, now)?;
+        Self::ensure_milestone_voting_active(project_key, milestone_key)?;
+
+        let vote_lookup_key = (who.clone(), project_key, milestone_key, round_key);
+        let old_vote = Self::user_votes(vote_lookup_key.clone()).ok_or(Error::<T>::VoteDoesNotExist)?;
+        ensure!(new_vote != Some(old_vote), Error::<T>::VoteUnchanged);
+
+        let own_weight = Self::milestone_vote_weight(project_key, round_key, &who)
+            .unwrap_or_else(|| (0_u32).into());
+        let mut vote_weight = match Self::user_vote_convictions(vote_lookup_key.clone()) {
+            Some((conviction, locked_amount)) => {
+                Self::conviction_vote_weight(own_weight, conviction, locked_amount)
+            }
+            None => own_weight,
+        };
+        let mut delegators: Vec<T::AccountId> = Vec::new();
+        if let Some(delegate) = VoteCastBy::<T>::take(vote_lookup_key.clone()) {
+            let delegate_vote_key = (delegate, project_key, milestone_key, round_key);
+            let mut delegate_delegators = DelegatedVotes::<T>::get(delegate_vote_key.clone());
+            delegate_delegators.retain(|delegator| delegator != &who);
+            if delegate_delegators.is_empty() {
+                DelegatedVotes::<T>::remove(delegate_vote_key);
+            } else {
+                DelegatedVotes::<T>::insert(delegate_vote_key, delegate_delegators);
+            }
+        } else {
+            delegators = DelegatedVotes::<T>::get(vote_lookup_key.clone());
+            for delegator in delegators.iter() {
+                vote_weight += Self::milestone_vote_weight(project_key, round_key, delegator)
+                    .unwrap_or_else(|| (0_u32).into());
+            }
+        }
+
+        let mut milestone_vote =
+            Self::milestone_votes((project_key, milestone_key)).ok_or(Error::<T>::KeyNotFound)?;
+        if old_vote {
+            milestone_vote.yay = milestone_vote.yay.saturating_sub(vote_weight);
+        } else {
+            milestone_vote.nay = milestone_vote.nay.saturating_sub(vote_weight);
+        }
+        match new_vote {
+            Some(true) => milestone_vote.yay += vote_weight,
+            Some(false) => milestone_vote.nay += vote_weight,
+            None => (),
+        }
+        <MilestoneVotes<T>>::insert((project_key, milestone_key), milestone_vote);
+
+        match new_vote {
+            Some(approve_milestone) => {
+                <UserVotes<T>>::insert(vote_lookup_key, approve_milestone);
+                for delegator in delegators.iter() {
+                    <UserVotes<T>>::insert(
+                        (delegator.clone(), project_key, milestone_key, round_key),
+                        approve_milestone,
+                    );
+                }
+            }
+            // The delegators are free to vote again once their delegate removed its vote
+            // The conviction lock of a removed vote runs until its period ends
+            None => {
+                <UserVotes<T>>::remove(vote_lookup_key.clone());
+                UserVoteConvictions::<T>::remove(vote_lookup_key.clone());
+                DelegatedVotes::<T>::remove(vote_lookup_key);
+                for delegator in delegators.iter() {
+                    let delegator_vote_key = (delegator.clone(), project_key, milestone_key, round_key);
+                    <UserVotes<T>>::remove(delegator_vote_key.clone());
+                    VoteCastBy::<T>::remove(delegator_vote_key);
+                }
+            }
+        }
+
+        Self::deposit_event(Event::VoteChanged(
+            who,
+            project_key,
+            milestone_key,
+            new_vote,
+            now,
+        ));
+
+        Ok(().into())
+    }
+
+    /// Lock `amount` of the voter's native funds for the period of its conviction.
+    fn lock_for_conviction(
+        who: &T::AccountId,
+        amount: BalanceOf<T>,
+        conviction: Conviction,
+        now: T::BlockNumber,
+    ) -> DispatchResult {
+        let unlock_at = now.saturating_add(
+            T::ConvictionVoteLockingPeriod::get().saturating_mul(conviction.lock_periods().into()),
+        );
+        let mut locks = Self::conviction_locks(who);
+        locks
+            .try_push((unlock_at, amount))
+            .map_err(|_| Error::<T>::TooManyConvictionLocks)?;
+        let locked_amount = locks
+            .iter()
+            .map(|(_, amount)| *amount)
+            .max()
+            .unwrap_or(amount);
+        ensure!(
+            T::NativeCurrency::free_balance(who) >= locked_amount,
+            Error::<T>::InsufficientBalanceForConviction
+        );
+
+        T::NativeCurrency::set_lock(CONVICTION_VOTE_LOCK_ID, who, locked_amount, WithdrawReasons::all());
+        ConvictionLocks::<T>::insert(who, locks);
+        ConvictionLocksExpiringAt::<T>::append(unlock_at, who);
+        Self::deposit_event(Event::ConvictionLocked(who.clone(), amount, unlock_at));
+        Ok(())
+    }
+
+    /// The weight of a conviction vote, only the part of the voter's own weight backed by
+    /// the locked native funds is multiplied.
+    fn conviction_vote_weight(
+        own_weight: BalanceOf<T>,
+        conviction: Conviction,
+        locked_amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let backed_weight = own_weight.min(locked_amount);
+        own_weight + backed_weight * conviction.multiplier().saturating_sub(1).into()
+    }
+
+    /// Release the conviction locks which end at `now`.
+    fn release_expired_conviction_locks(now: T::BlockNumber) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let accounts = ConvictionLocksExpiringAt::<T>::take(now);
+
+        for who in accounts.into_iter() {
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+            let mut locks = Self::conviction_locks(&who);
+            locks.retain(|(unlock_at, _)| unlock_at > &now);
+            let locked_amount = match locks.iter().map(|(_, amount)| *amount).max() {
+                Some(locked_amount) => {
+                    T::NativeCurrency::set_lock(CONVICTION_VOTE_LOCK_ID, &who, locked_amount, WithdrawReasons::all());
+                    ConvictionLocks::<T>::insert(&who, locks);
+                    locked_amount
+                }
+                None => {
+                    T::NativeCurrency::remove_lock(CONVICTION_VOTE_LOCK_ID, &who);
+                    ConvictionLocks::<T>::remove(&who);
+                    (0_u32).into()
+                }
+            };
+            Self::deposit_event(Event::ConvictionLockReleased(who, locked_amount));
+        }
+
+        weight
+    }
+
+    /// The most delegated votes a delegate can cast at once, delegations on the project
+    /// and on every project are bounded separately.
+    pub fn max_delegated_votes() -> u32 {
+        T::MaxDelegatorsPerDelegate::get().saturating_mul(2)
+    }
+
+    /// The account voting on milestones of a project on behalf of `who`, if any.
+    pub fn vote_delegate(who: &T::AccountId, project_key: ProjectKey) -> Option<T::AccountId> {
+        Self::project_vote_delegations(project_key, who).or_else(|| Self::vote_delegations(who))
+    }
+
+    pub fn do_finalise_milestone_voting(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+    ) -> DispatchResultWithPostInfo {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(
+            project.initiator == who,
+            Error::<T>::OnlyInitiatorOrAdminCanApproveMilestone
+        );
+
+        Self::finalise_milestone(project_key, milestone_key, project)
+    }
+
+    /// Apply the outcome of the vote on a milestone.
+    /// Finalising requires the quorum to be reached or the voting window to be over.
+    fn finalise_milestone(
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        project: ProjectOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let vote_lookup_key = (project_key, milestone_key);
+        Self::ensure_milestone_voting_active(project_key, milestone_key)?;
+        let voting_round_key = Self::milestone_voting_rounds(vote_lookup_key);
+        let voting_round = voting_round_key.and_then(|round_key| Self::rounds(round_key));
+        let total_contribution_amount: BalanceOf<T> =
+            match voting_round_key.and_then(Self::milestone_vote_snapshot_totals) {
+                Some((total_vote_weight, _)) => total_vote_weight,
+                None => Self::get_total_project_contributions(project_key)?,
+            };
+
+        let mut milestones = Vec::new();
+        // set is_approved
+        for mut milestone in project.milestones.into_iter() {
+            if milestone.milestone_key == milestone_key {
+                let vote = Self::milestone_votes(vote_lookup_key).ok_or(Error::<T>::KeyNotFound)?;
+                let total_votes = vote.yay + vote.nay;
+
+                // Once the voting window is over the votes which were cast are enough
+                let voting_window_expired = match &voting_round {
+                    Some(round) => round.end < now,
+                    None => false,
+                };
+                let quorum_reached = total_votes
+                    >= Self::milestone_voting_quorum().mul_ceil(total_contribution_amount);
+                ensure!(
+                    quorum_reached || voting_window_expired,
+                    Error::<T>::MilestoneVotingNotComplete
+                );
+
+                if vote.yay > Self::milestone_approval_threshold().mul_floor(total_votes) {
+                    milestone.is_approved = true;
+                    let updated_vote = Vote {
+                        yay: vote.yay,
+                        nay: vote.nay,
+                        is_approved: true,
+                    };
+                    Self::deposit_event(Event::MilestoneApproved(project.initiator.clone(), project_key, milestone_key, now));
+
+                    Self::close_milestone_submission(
+                        vote_lookup_key,
+                        MilestoneSubmissionStatus::Approved(now),
+                        updated_vote.clone(),
+                    );
+                    <MilestoneVotes<T>>::insert(vote_lookup_key, updated_vote);
+                    OverdueMilestoneVotes::<T>::remove(vote_lookup_key);
+                    Self::refresh_withdrawal_expiry(project_key, now);
+                } else {
+                    Self::close_milestone_submission(
+                        vote_lookup_key,
+                        MilestoneSubmissionStatus::Rejected(now),
+                        vote,
+                    );
+                    Self::deposit_event(Event::MilestoneRejected(project.initiator.clone(), project_key, milestone_key, now));
+                }
+            }
+            milestones.push(milestone.clone());
+        }
+
+        // The project is completed once every milestone is approved
+        let is_completed = milestones.iter().all(|milestone| milestone.is_approved);
+        let initiator = project.initiator.clone();
+
+        // Update project milestones
+        let updated_project = Project {
+            name: project.name,
+            logo: project.logo,
+            description: project.description,
+            website: project.website,
+            milestones,
+            required_funds: project.required_funds,
+            currency_id: project.currency_id,
+            withdrawn_funds: project.withdrawn_funds,
+            initiator: project.initiator,
+            create_block_number: project.create_block_number,
+            approved_for_funding: project.approved_for_funding,
+            funding_threshold_met: project.funding_threshold_met,
+            cancelled: project.cancelled,
+        };
+        // Add proposal to list
+        <Projects<T>>::insert(project_key, updated_project);
+        if is_completed {
+            Self::release_deposits(project_key, &initiator);
+        }
+
+        // The vote no longer has to be finalised when the voting window ends
+        if let Some(round) = voting_round {
+            MilestoneVotesEndingAt::<T>::mutate_exists(round.end, |milestone_votes| {
+                if let Some(keys) = milestone_votes {
+                    keys.retain(|key| key != &vote_lookup_key);
+                    if keys.is_empty() {
+                        *milestone_votes = None;
+                    }
+                }
+            });
+        }
+
+        Ok(().into())
+    }
+
+    /// Milestones submitted before submissions were recorded can always be voted on.
+    fn ensure_milestone_voting_active(
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+    ) -> Result<(), Error<T>> {
+        let is_voting = Self::milestone_submissions((project_key, milestone_key))
+            .last()
+            .map_or(true, |submission| submission.status == MilestoneSubmissionStatus::Voting);
+        ensure!(is_voting, Error::<T>::MilestoneVotingNotActive);
+        Ok(())
+    }
+
+    /// Record the outcome of the latest submission of a milestone if it is still being voted on.
+    fn close_milestone_submission(
+        vote_lookup_key: (ProjectKey, MilestoneKey),
+        status: MilestoneSubmissionStatus<T::BlockNumber>,
+        votes: Vote<BalanceOf<T>>,
+    ) {
+        let closed_round_key = MilestoneSubmissions::<T>::mutate(vote_lookup_key, |submissions| {
+            match submissions.last_mut() {
+                Some(submission) if submission.status == MilestoneSubmissionStatus::Voting => {
+                    submission.status = status;
+                    submission.votes = votes;
+                    Some(submission.round_key)
+                }
+                _ => None,
+            }
+        });
+
+        // The snapshot is no longer needed once the vote is closed
+        if let Some(round_key) = closed_round_key {
+            MilestoneVoteSnapshotTotals::<T>::remove(round_key);
+            MilestoneVoteSnapshots::<T>::drain_prefix(round_key).for_each(drop);
+        }
+    }
+
+    /// Finalise the milestone votes whose voting window ended in the previous block.
+    /// Votes over `MaxMilestoneFinalisationsPerBlock` are carried over to the next block.
+    fn finalise_ended_milestone_votes(now: T::BlockNumber) -> Weight {
+        let ended_at = now.saturating_sub(1_u32.into());
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let mut milestone_votes = MilestoneVotesEndingAt::<T>::take(ended_at);
+        if milestone_votes.is_empty() {
+            return weight;
+        }
+
+        let max_finalisations = T::MaxMilestoneFinalisationsPerBlock::get() as usize;
+        if milestone_votes.len() > max_finalisations {
+            let carried_over = milestone_votes.split_off(max_finalisations);
+            MilestoneVotesEndingAt::<T>::mutate(now, |keys| keys.extend(carried_over));
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        for (project_key, milestone_key) in milestone_votes.into_iter() {
+            // Finalising clears the snapshot of the voting round
+            let voters = Self::milestone_voting_rounds((project_key, milestone_key))
+                .and_then(Self::milestone_vote_snapshot_totals)
+                .map_or(0, |(_, voters)| voters);
+            weight = weight.saturating_add(<T as Config>::WeightInfo::finalise_milestone_voting(voters));
+            // Milestones approved in the meantime are left untouched
+            let already_approved = Self::milestone_votes((project_key, milestone_key))
+                .map_or(false, |vote| vote.is_approved);
+            if already_approved {
+                continue;
+            }
+            if let Some(project) = Self::projects(project_key) {
+                let _ = Self::finalise_milestone(project_key, milestone_key, project);
+            }
+        }
+
+        weight
+    }
+
+    /// Open a vote on the milestones whose deadline passed in the previous block.
+    fn check_overdue_milestones(now: T::BlockNumber) -> Weight {
+        let due_at = now.saturating_sub(1_u32.into());
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let due_milestones = MilestonesDueAt::<T>::take(due_at);
+
+        for (project_key, milestone_key) in due_milestones.into_iter() {
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            // The deadline was changed by a project update or an extension
+            if Self::milestone_deadlines((project_key, milestone_key)) != Some(due_at) {
+                continue;
+            }
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            let project = match Self::projects(project_key) {
+                Some(project) => project,
+                None => continue,
+            };
+            let is_approved = project
+                .milestones
+                .iter()
+                .any(|milestone| milestone.milestone_key == milestone_key && milestone.is_approved);
+            // Projects which are not funded yet are checked again when they are approved
+            if project.cancelled || is_approved || !project.funding_threshold_met {
+                continue;
+            }
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            Self::open_overdue_milestone_vote(project_key, milestone_key, due_at);
+        }
+
+        weight
+    }
+
+    fn open_overdue_milestone_vote(
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        deadline: T::BlockNumber,
+    ) {
+        let vote_lookup_key = (project_key, milestone_key);
+        if OverdueMilestoneVotes::<T>::contains_key(vote_lookup_key) {
+            return;
+        }
+        let vote = Vote {
+            yay: (0_u32).into(),
+            nay: (0_u32).into(),
+            is_approved: false,
+        };
+        OverdueMilestoneVotes::<T>::insert(vote_lookup_key, vote);
+        Self::deposit_event(Event::MilestoneOverdue(project_key, milestone_key, deadline));
+    }
+
+    fn new_overdue_milestone_vote(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        milestone_key: MilestoneKey,
+        refund: bool,
+    ) -> DispatchResultWithPostInfo {
+        let vote_lookup_key = (project_key, milestone_key);
+        let vote = Self::overdue_milestone_votes(vote_lookup_key).ok_or(Error::<T>::MilestoneNotOverdue)?;
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(!project.cancelled, Error::<T>::ProjectCancelled);
+        let deadline = Self::milestone_deadlines(vote_lookup_key).ok_or(Error::<T>::KeyNotFound)?;
+        let contribution_amount: BalanceOf<T> =
+            Self::contributions(project_key, &who).ok_or(Error::<T>::OnlyContributorsCanVote)?;
+
+        let user_vote_lookup_key = (who.clone(), project_key, milestone_key, deadline);
+        ensure!(
+            !UserOverdueMilestoneVotes::<T>::contains_key(&user_vote_lookup_key),
+            Error::<T>::VoteAlreadyExists
+        );
+        UserOverdueMilestoneVotes::<T>::insert(user_vote_lookup_key, refund);
+
+        let updated_vote = if refund {
+            Vote {
+                yay: vote.yay + contribution_amount,
+                nay: vote.nay,
+                is_approved: vote.is_approved,
+            }
+        } else {
+            Vote {
+                yay: vote.yay,
+                nay: vote.nay + contribution_amount,
+                is_approved: vote.is_approved,
+            }
+        };
+        let now = <frame_system::Pallet<T>>::block_number();
+        Self::deposit_event(Event::OverdueMilestoneVoteComplete(
+            who,
+            project_key,
+            milestone_key,
+            refund,
+            now,
+        ));
+
+        // The vote is settled as soon as a majority of the contributions agree
+        let total_contribution_amount: BalanceOf<T> =
+            Self::get_total_project_contributions(project_key)?;
+        let majority = T::OverdueMilestoneMajority::get().mul_floor(total_contribution_amount);
+        if updated_vote.yay > majority {
+            OverdueMilestoneVotes::<T>::remove(vote_lookup_key);
+            MilestoneDeadlines::<T>::remove(vote_lookup_key);
+            Self::do_refund(project_key)?;
+        } else if updated_vote.nay > majority {
+            OverdueMilestoneVotes::<T>::remove(vote_lookup_key);
+            let extended_deadline = now.saturating_add(T::MilestoneDeadlineExtension::get());
+            MilestoneDeadlines::<T>::insert(vote_lookup_key, extended_deadline);
+            MilestonesDueAt::<T>::append(extended_deadline, vote_lookup_key);
+            Self::deposit_event(Event::MilestoneDeadlineExtended(
+                project_key,
+                milestone_key,
+                extended_deadline,
+            ));
+        } else {
+            OverdueMilestoneVotes::<T>::insert(vote_lookup_key, updated_vote);
+        }
+
+        Ok(().into())
+    }
+
+    /// Returns up to `limit` projects, starting at `start_key`.
+    pub fn get_projects(start_key: ProjectKey, limit: u32) -> Vec<(ProjectKey, ProjectOf<T>)> {
+        let end_key = start_key
+            .saturating_add(limit)
+            .min(ProjectCount::<T>::get());
+        (start_key..end_key)
+            .filter_map(|project_key| Self::projects(project_key).map(|project| (project_key, project)))
+            .collect()
+    }
+
+    /// Returns every milestone vote `who` made on a project as (milestone, round, approved).
+    pub fn get_user_milestone_votes(
+        project_key: ProjectKey,
+        who: T::AccountId,
+    ) -> Vec<(MilestoneKey, RoundKey, bool)> {
+        let project = match Self::projects(project_key) {
+            Some(project) => project,
+            None => return Vec::new(),
+        };
+        // Milestones submitted before submissions were recorded were voted on in the
+        // latest voting round of the project
+        let legacy_round_key = Self::project_rounds(project_key, RoundType::VotingRound);
+
+        let mut votes = Vec::new();
+        for milestone in project.milestones.iter() {
+            let vote_lookup_key = (project_key, milestone.milestone_key);
+            let mut round_keys: Vec<RoundKey> = Self::milestone_submissions(vote_lookup_key)
+                .iter()
+                .map(|submission| submission.round_key)
+                .collect();
+            if round_keys.is_empty() {
+                round_keys.extend(
+                    Self::milestone_voting_rounds(vote_lookup_key).or(legacy_round_key),
+                );
+            }
+            for round_key in round_keys.iter() {
+                let user_vote_lookup_key = (who.clone(), project_key, milestone.milestone_key, *round_key);
+                if let Some(approve_milestone) = Self::user_votes(user_vote_lookup_key) {
+                    votes.push((milestone.milestone_key, *round_key, approve_milestone));
+                }
+            }
+        }
+        votes
+    }
+
+    /// Returns the latest round of a project which is currently open.
+    pub fn get_active_round(project_key: ProjectKey) -> Option<(RoundKey, RoundOf<T>)> {
+        [RoundType::ContributionRound, RoundType::VotingRound]
+            .iter()
+            .filter_map(|round_type| Self::processing_round(project_key, *round_type))
+            .max_by_key(|(round_key, _)| *round_key)
+    }
+
+    /// Ensure the project is not part of another round of the given type which has not ended yet.
+    fn ensure_not_in_open_round(
+        project_key: ProjectKey,
+        round_type: RoundType,
+        round_key: RoundKey,
+    ) -> DispatchResult {
+        let now = <frame_system::Pallet<T>>::block_number();
+        if let Some(latest_round_key) = Self::project_rounds(project_key, round_type) {
+            if let Some(latest_round) = Self::rounds(latest_round_key) {
+                ensure!(
+                    latest_round_key == round_key
+                        || latest_round.is_canceled
+                        || latest_round.end < now
+                        || !latest_round.project_keys.contains(&project_key),
+                    Error::<T>::ProjectAlreadyInRound
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Make `round_key` the latest round of its type the project is scheduled in.
+    fn index_project_round(project_key: ProjectKey, round_type: RoundType, round_key: RoundKey) {
+        match Self::project_rounds(project_key, round_type) {
+            Some(latest_round_key) if latest_round_key == round_key => return,
+            Some(latest_round_key) => {
+                ProjectPreviousRounds::<T>::insert(project_key, round_key, latest_round_key)
+            }
+            None => (),
+        }
+        ProjectRounds::<T>::insert(project_key, round_type, round_key);
+    }
+
+    /// Fall back to the round the project was scheduled in before `round_key` once it left that round.
+    fn unindex_project_round(project_key: ProjectKey, round_type: RoundType, round_key: RoundKey) {
+        let previous_round_key = ProjectPreviousRounds::<T>::take(project_key, round_key);
+        if Self::project_rounds(project_key, round_type) == Some(round_key) {
+            match previous_round_key {
+                Some(previous_round_key) => {
+                    ProjectRounds::<T>::insert(project_key, round_type, previous_round_key)
+                }
+                None => ProjectRounds::<T>::remove(project_key, round_type),
+            }
+        }
+    }
+
+    /// Returns the latest round of the given type a project is part of, if it is currently open.
+    fn processing_round(
+        project_key: ProjectKey,
+        round_type: RoundType,
+    ) -> Option<(RoundKey, RoundOf<T>)> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let round_key = Self::project_rounds(project_key, round_type)?;
+        let round = Self::rounds(round_key)?;
+        // The project may have been removed from the round before it started
+        if !round.is_canceled
+            && round.start < now
+            && round.end > now
+            && round.project_keys.contains(&project_key)
+        {
+            Some((round_key, round))
+        } else {
+            None
+        }
+    }
+
+    /// The funds of approved milestones which the initiator has not withdrawn yet.
+    pub fn get_withdrawable_funds(project_key: ProjectKey) -> Result<BalanceOf<T>, Error<T>> {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        let total_funds: BalanceOf<T> = Self::get_total_project_funds(project_key)?;
+
+        let mut unlocked_funds: BalanceOf<T> = (0_u32).into();
+        for milestone in project.milestones.iter() {
+            if milestone.is_approved {
+                unlocked_funds += (total_funds
+                    * milestone.percentage_to_unlock.into())
+                    / 100u32.into();
+            }
+        }
+
+        Ok(unlocked_funds.saturating_sub(project.withdrawn_funds))
+    }
+
+    pub fn new_withdrawal(who: T::AccountId, project_key: ProjectKey) -> DispatchResultWithPostInfo {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(
+            Self::has_project_role(&who, project_key, &project, ProjectRole::Withdraw),
+            Error::<T>::InvalidAccount
+        );
+        if let Some(expires_at) = Self::project_withdrawal_expiry(project_key) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now <= expires_at, Error::<T>::WithdrawalExpirationExceed);
+        }
+
+        let available_funds: BalanceOf<T> = Self::get_withdrawable_funds(project_key)?;
+        ensure!(
+            available_funds > (0_u32).into(),
+            Error::<T>::NoAvailableFundsToWithdraw
+        );
+
+        // Vesting withdrawals stay in the project account until they are claimed
+        if let Some(vesting_period) = Self::project_vesting_periods(project_key) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            VestingSchedules::<T>::append(
+                project_key,
+                VestingSchedule {
+                    total: available_funds,
+                    claimed: (0_u32).into(),
+                    start: now,
+                    period: vesting_period,
+                },
+            );
+            Self::deposit_event(Event::ProjectFundsVesting(
+                who.clone(),
+                project_key,
+                available_funds,
+                project.currency_id,
+                now.saturating_add(vesting_period),
+            ));
+        } else {
+            // Withdrawals go to the beneficiary of the project if one is set
+            let beneficiary =
+                Self::project_beneficiaries(project_key).unwrap_or_else(|| project.initiator.clone());
+            T::MultiCurrency::transfer(
+                project.currency_id,
+                &Self::project_account_id(project_key),
+                &beneficiary,
+                available_funds,
+            )?;
+        }
+
+        // Update project withdrawn funds
+        let updated_project = Project {
+            name: project.name,
+            logo: project.logo,
+            description: project.description,
+            website: project.website,
+            milestones: project.milestones,
             required_funds: project.required_funds,
             currency_id: project.currency_id,
-            withdrawn_funds: project.withdrawn_funds,
+            withdrawn_funds: available_funds + project.withdrawn_funds,
             initiator: project.initiator,
             create_block_number: project.create_block_number,
             approved_for_funding: project.approved_for_funding,
@@ -906,249 +3759,361 @@ impl<T: Config> Pallet<T> {
         };
         // Add proposal to list
         <Projects<T>>::insert(project_key, updated_project);
-        Self::deposit_event(Event::ProjectApproved(round_key, project_key));
+        Self::deposit_event(Event::ProjectFundsWithdrawn(
+            who,
+            project_key,
+            available_funds,
+            project.currency_id,
+        ));
+
         Ok(().into())
     }
 
-    pub fn new_milestone_submission(
-        who: T::AccountId,
-        project_key: ProjectKey,
-        milestone_key: MilestoneKey,
-    ) -> DispatchResultWithPostInfo {
-        let now = <frame_system::Pallet<T>>::block_number();
-        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
-
-        ensure!(project.initiator == who, Error::<T>::UserIsNotInitator);
-        ensure!(
-            project.funding_threshold_met,
-            Error::<T>::OnlyApprovedProjectsCanSubmitMilestones
-        );
-        let end = now + MilestoneVotingWindow::<T>::get().into();
-        let key = RoundCount::<T>::get();
-        let round = RoundOf::<T>::new(now, end, vec![project_key], RoundType::VotingRound);
-        let next_key = key.checked_add(1).ok_or(Error::<T>::Overflow)?;
-
-        let vote = Vote {
-            yay: (0_u32).into(),
-            nay: (0_u32).into(),
-            is_approved: false,
-        };
-        let vote_lookup_key = (project_key, milestone_key);
-        <MilestoneVotes<T>>::insert(vote_lookup_key, vote);
-        Self::deposit_event(Event::MilestoneSubmitted(who, project_key, milestone_key));
-        // Add proposal round to list
-        <Rounds<T>>::insert(key, Some(round));
-        RoundCount::<T>::put(next_key);
-        Self::deposit_event(Event::VotingRoundCreated(key, vec![project_key]));
-        Ok(().into())
+    /// The part of a vesting schedule which vested by `now`, including what was already claimed.
+    pub fn vested_funds(
+        schedule: &VestingSchedule<BalanceOf<T>, T::BlockNumber>,
+        now: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        let elapsed = now.saturating_sub(schedule.start);
+        if elapsed >= schedule.period {
+            return schedule.total;
+        }
+        Perbill::from_rational(
+            elapsed.saturated_into::<u128>(),
+            schedule.period.saturated_into::<u128>(),
+        )
+        .mul_floor(schedule.total)
     }
 
-    pub fn new_milestone_vote(
-        who: T::AccountId,
-        project_key: ProjectKey,
-        milestone_key: MilestoneKey,
-        approve_milestone: bool,
-    ) -> DispatchResultWithPostInfo {
-        let project_count = ProjectCount::<T>::get();
-        ensure!(project_key < project_count, Error::<T>::InvalidParam);
+    /// The vested funds of a project which have not been claimed yet.
+    pub fn get_claimable_funds(project_key: ProjectKey) -> BalanceOf<T> {
         let now = <frame_system::Pallet<T>>::block_number();
+        Self::vesting_schedules(project_key)
+            .iter()
+            .fold((0_u32).into(), |claimable: BalanceOf<T>, schedule| {
+                claimable.saturating_add(Self::vested_funds(schedule, now).saturating_sub(schedule.claimed))
+            })
+    }
 
-        // round list must be not none
-        let round_key = RoundCount::<T>::get();
-        ensure!(round_key > 0, Error::<T>::NoActiveRound);
+    fn do_claim_vested_funds(who: T::AccountId, project_key: ProjectKey) -> DispatchResultWithPostInfo {
         let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(
+            Self::has_project_role(&who, project_key, &project, ProjectRole::Withdraw),
+            Error::<T>::InvalidAccount
+        );
 
-        // Find processing round
-        let mut latest_round: Option<RoundOf<T>> = None;
-        let mut latest_round_key = 0;
-        for i in (0..round_key).rev() {
-            let round = Self::rounds(i).ok_or(Error::<T>::KeyNotFound)?;
-            if !round.is_canceled
-                && round.start < now
-                && round.end > now
-                && round.project_keys.contains(&project_key)
-            {
-                latest_round = Some(round);
-                latest_round_key = i;
-                break;
-            }
-        }
-        let round = latest_round.ok_or(Error::<T>::RoundNotProcessing)?;
-
-        let mut existing_contributor = false;
-        let mut contribution_amount: BalanceOf<T> = (0_u32).into();
-
-        // Find previous contribution by account_id
-        // If you have contributed before, then add to that contribution. Otherwise join the list.
-        for contribution in project.contributions.iter() {
-            if contribution.account_id == who {
-                existing_contributor = true;
-                contribution_amount = contribution.value;
-                break;
-            }
+        let now = <frame_system::Pallet<T>>::block_number();
+        let mut schedules = Self::vesting_schedules(project_key);
+        let mut claimable_funds: BalanceOf<T> = (0_u32).into();
+        for schedule in schedules.iter_mut() {
+            let vested_funds = Self::vested_funds(schedule, now);
+            claimable_funds += vested_funds.saturating_sub(schedule.claimed);
+            schedule.claimed = vested_funds;
         }
+        ensure!(
+            claimable_funds > (0_u32).into(),
+            Error::<T>::NoVestedFundsToClaim
+        );
 
-        ensure!(existing_contributor, Error::<T>::OnlyContributorsCanVote);
-        let vote_lookup_key = (who.clone(), project_key, milestone_key, latest_round_key);
-
-        let vote_exists = UserVotes::<T>::contains_key(vote_lookup_key.clone());
-        ensure!(!vote_exists, Error::<T>::VoteAlreadyExists);
-
-        <UserVotes<T>>::insert(vote_lookup_key, approve_milestone);
-
-        let user_milestone_vote = Self::milestone_votes((project_key, milestone_key)).ok_or(Error::<T>::KeyNotFound)?;
+        let beneficiary =
+            Self::project_beneficiaries(project_key).unwrap_or_else(|| project.initiator.clone());
+        T::MultiCurrency::transfer(
+            project.currency_id,
+            &Self::project_account_id(project_key),
+            &beneficiary,
+            claimable_funds,
+        )?;
 
-        if approve_milestone {
-            let updated_vote = Vote {
-                yay: user_milestone_vote.yay + contribution_amount,
-                nay: user_milestone_vote.nay,
-                is_approved: user_milestone_vote.is_approved,
-            };
-            <MilestoneVotes<T>>::insert((project_key, milestone_key), updated_vote)
+        // Fully claimed schedules are dropped
+        schedules.retain(|schedule| schedule.claimed < schedule.total);
+        if schedules.is_empty() {
+            VestingSchedules::<T>::remove(project_key);
         } else {
-            let updated_vote = Vote {
-                yay: user_milestone_vote.yay,
-                nay: user_milestone_vote.nay + contribution_amount,
-                is_approved: user_milestone_vote.is_approved,
-            };
-            <MilestoneVotes<T>>::insert((project_key, milestone_key), updated_vote)
+            VestingSchedules::<T>::insert(project_key, schedules);
         }
-
-        <Rounds<T>>::insert(round_key - 1, Some(round));
-        Self::deposit_event(Event::VoteComplete(
+        Self::deposit_event(Event::VestedFundsClaimed(
             who,
             project_key,
-            milestone_key,
-            approve_milestone,
-            now,
+            claimable_funds,
+            project.currency_id,
         ));
 
         Ok(().into())
     }
 
-    pub fn do_finalise_milestone_voting(
-        who: T::AccountId,
-        project_key: ProjectKey,
-        milestone_key: MilestoneKey,
-    ) -> DispatchResultWithPostInfo {
-        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
-        ensure!(
-            project.initiator == who,
-            Error::<T>::OnlyInitiatorOrAdminCanApproveMilestone
-        );
+    /// Give the initiator `WithdrawalExpiration` blocks from now to withdraw the approved funds.
+    fn refresh_withdrawal_expiry(project_key: ProjectKey, now: T::BlockNumber) {
+        let withdrawal_expiration =
+            WithdrawalExpiration::<T>::get().min(T::MaxWithdrawalExpiration::get());
+        if withdrawal_expiration == (0_u32).into() {
+            return;
+        }
+        let expires_at = now.saturating_add(withdrawal_expiration);
+        ProjectWithdrawalExpiry::<T>::insert(project_key, expires_at);
+        WithdrawalsExpiringAt::<T>::append(expires_at, project_key);
+    }
 
-        let total_contribution_amount: BalanceOf<T> =
-            Self::get_total_project_contributions(project_key)?;
+    /// Reclaim the funds of projects whose withdrawal expiration ended in the previous block.
+    fn reclaim_expired_withdrawals(now: T::BlockNumber) -> Weight {
+        let expired_at = now.saturating_sub(1_u32.into());
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let project_keys = WithdrawalsExpiringAt::<T>::take(expired_at);
+
+        for project_key in project_keys.into_iter() {
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            // The expiry was pushed back by a later milestone approval
+            if Self::project_withdrawal_expiry(project_key) != Some(expired_at) {
+                continue;
+            }
+            weight = weight.saturating_add(<T as Config>::WeightInfo::reclaim_expired_funds(
+                Self::project_contributor_count(project_key),
+            ));
+            if Self::reclaim_expired_funds(project_key).is_ok() {
+                ProjectWithdrawalExpiry::<T>::remove(project_key);
+            } else {
+                // Retry in the next block rather than leaving the funds unclaimable
+                ProjectWithdrawalExpiry::<T>::insert(project_key, now);
+                WithdrawalsExpiringAt::<T>::append(now, project_key);
+            }
+        }
 
-        let mut milestones = Vec::new();
-        // set is_approved
-        for mut milestone in project.milestones.into_iter() {
-            if milestone.milestone_key == milestone_key {
-                let vote_lookup_key = (project_key, milestone_key);
-                let vote = Self::milestone_votes(vote_lookup_key).ok_or(Error::<T>::KeyNotFound)?;
-                let total_votes = vote.yay + vote.nay;
-                ensure!(
-                    total_votes == total_contribution_amount,
-                    Error::<T>::MilestoneVotingNotComplete
-                );
-                if vote.yay > vote.nay {
-                    milestone.is_approved = true;
-                    let updated_vote = Vote {
-                        yay: vote.yay,
-                        nay: vote.nay,
-                        is_approved: true,
-                    };
-                    let now = <frame_system::Pallet<T>>::block_number();
-                    Self::deposit_event(Event::MilestoneApproved(project.initiator.clone(), project_key, milestone_key, now));
+        weight
+    }
 
-                    <MilestoneVotes<T>>::insert(vote_lookup_key, updated_vote);
+    /// Send the funds the initiator did not withdraw in time to `Config::ExpiredFundsDestination`.
+    #[transactional]
+    fn reclaim_expired_funds(project_key: ProjectKey) -> DispatchResult {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        let expired_funds: BalanceOf<T> = Self::get_withdrawable_funds(project_key)?;
+        if expired_funds == (0_u32).into() {
+            return Ok(());
+        }
+
+        let destination = T::ExpiredFundsDestination::get();
+        let mut reclaimed_funds: BalanceOf<T> = (0_u32).into();
+        match destination {
+            ExpiredFundsDestination::Contributors => {
+                let total_contribution_amount: BalanceOf<T> =
+                    Self::get_total_project_contributions(project_key)?;
+                for (who, value) in Contributions::<T>::iter_prefix(project_key) {
+                    let share: BalanceOf<T> =
+                        Perbill::from_rational(value, total_contribution_amount) * expired_funds;
+                    T::MultiCurrency::transfer(
+                        project.currency_id,
+                        &Self::project_account_id(project_key),
+                        &who,
+                        share,
+                    )?;
+                    reclaimed_funds += share;
                 }
             }
-            milestones.push(milestone.clone());
+            ExpiredFundsDestination::Treasury => {
+                T::MultiCurrency::transfer(
+                    project.currency_id,
+                    &Self::project_account_id(project_key),
+                    &T::TreasuryAccount::get(),
+                    expired_funds,
+                )?;
+                reclaimed_funds = expired_funds;
+            }
         }
 
-        // Update project milestones
+        // Reclaimed funds can no longer be withdrawn
         let updated_project = Project {
             name: project.name,
             logo: project.logo,
             description: project.description,
             website: project.website,
-            milestones,
-            contributions: project.contributions,
+            milestones: project.milestones,
             required_funds: project.required_funds,
             currency_id: project.currency_id,
-            withdrawn_funds: project.withdrawn_funds,
+            withdrawn_funds: reclaimed_funds + project.withdrawn_funds,
             initiator: project.initiator,
             create_block_number: project.create_block_number,
             approved_for_funding: project.approved_for_funding,
             funding_threshold_met: project.funding_threshold_met,
             cancelled: project.cancelled,
         };
-        // Add proposal to list
         <Projects<T>>::insert(project_key, updated_project);
+        Self::deposit_event(Event::ExpiredFundsReclaimed(
+            project_key,
+            reclaimed_funds,
+            destination,
+        ));
 
-        Ok(().into())
+        Ok(())
     }
 
-    pub fn new_withdrawal(who: T::AccountId, project_key: ProjectKey) -> DispatchResultWithPostInfo {
-        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
-        ensure!(who == project.initiator, Error::<T>::InvalidAccount);
-        let total_contribution_amount: BalanceOf<T> =
-            Self::get_total_project_contributions(project_key)?;
+    /// The deposit for storing `items` taking up `bytes` in total.
+    fn storage_deposit(items: u32, bytes: usize) -> BalanceOf<T> {
+        T::DepositPerItem::get()
+            .saturating_mul(items.into())
+            .saturating_add(T::DepositPerByte::get().saturating_mul((bytes as u32).into()))
+    }
 
-        let mut unlocked_funds: BalanceOf<T> = (0_u32).into();
-        for milestone in project.milestones.clone() {
-            if milestone.is_approved {
-                unlocked_funds += (total_contribution_amount
-                    * milestone.percentage_to_unlock.into())
-                    / 100u32.into();
-            }
+    /// Return the storage deposits of a completed or refunded project to the initiator.
+    fn release_deposits(project_key: ProjectKey, initiator: &T::AccountId) {
+        let deposit = ProjectDeposits::<T>::take(project_key)
+            .saturating_add(WhitelistDeposits::<T>::take(project_key));
+        if deposit > (0_u32).into() {
+            T::MultiCurrency::unreserve(CurrencyId::Native, initiator, deposit);
+            Self::deposit_event(Event::ProjectDepositsReleased(project_key, deposit));
         }
+    }
 
-        let available_funds: BalanceOf<T> = unlocked_funds - project.withdrawn_funds;
+    fn do_reject_project(project_key: ProjectKey) -> DispatchResultWithPostInfo {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(!project.cancelled, Error::<T>::ProjectCancelled);
         ensure!(
-            available_funds > (0_u32).into(),
-            Error::<T>::NoAvailableFundsToWithdraw
+            ProjectContributionsTotal::<T>::get(project_key) == (0_u32).into(),
+            Error::<T>::ProjectHasContributions
         );
 
-        T::MultiCurrency::transfer(
-            project.currency_id,
-            &Self::project_account_id(project_key),
+        let deposit = ProjectDeposits::<T>::take(project_key)
+            .saturating_add(WhitelistDeposits::<T>::take(project_key));
+        T::MultiCurrency::repatriate_reserved(
+            CurrencyId::Native,
             &project.initiator,
-            available_funds,
+            &T::TreasuryAccount::get(),
+            deposit,
+            BalanceStatus::Free,
         )?;
+        <WhitelistSpots<T>>::remove(project_key);
 
-        // Update project withdrawn funds
         let updated_project = Project {
             name: project.name,
             logo: project.logo,
             description: project.description,
             website: project.website,
             milestones: project.milestones,
-            contributions: project.contributions,
             required_funds: project.required_funds,
             currency_id: project.currency_id,
-            withdrawn_funds: available_funds + project.withdrawn_funds,
+            withdrawn_funds: project.withdrawn_funds,
             initiator: project.initiator,
             create_block_number: project.create_block_number,
             approved_for_funding: project.approved_for_funding,
             funding_threshold_met: project.funding_threshold_met,
-            cancelled: project.cancelled,
+            cancelled: true,
         };
-        // Add proposal to list
         <Projects<T>>::insert(project_key, updated_project);
-        Self::deposit_event(Event::ProjectFundsWithdrawn(
-            who,
-            project_key,
-            available_funds,
-            project.currency_id,
-        ));
+        Self::deposit_event(Event::ProjectRejected(project_key, deposit));
+
+        Ok(().into())
+    }
+
+    /// Close the votes of no confidence whose voting period ended in the previous block.
+    /// Votes which reached the threshold already refunded the project, the others expire.
+    fn expire_ended_no_confidence_votes(now: T::BlockNumber) -> Weight {
+        let ended_at = now.saturating_sub(1_u32.into());
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let project_keys = NoConfidenceVotesEndingAt::<T>::take(ended_at);
+
+        for project_key in project_keys.into_iter() {
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            // The vote was closed by a refund in the meantime
+            if Self::no_confidence_round_ends(project_key) != Some(ended_at) {
+                continue;
+            }
+            weight = weight.saturating_add(T::DbWeight::get().writes(2));
+            NoConfidenceRoundEnds::<T>::remove(project_key);
+            NoConfidenceVotes::<T>::remove(project_key);
+            Self::deposit_event(Event::NoConfidenceRoundExpired(project_key));
+        }
+
+        weight
+    }
+
+    fn new_no_confidence_round(who: T::AccountId, project_key: ProjectKey) -> DispatchResultWithPostInfo {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(!project.cancelled, Error::<T>::ProjectCancelled);
+        // Only funds which are locked in the project can be refunded
+        ensure!(project.funding_threshold_met, Error::<T>::ProjectNotFunded);
+        ensure!(
+            !NoConfidenceVotes::<T>::contains_key(project_key),
+            Error::<T>::NoConfidenceRoundAlreadyRaised
+        );
+        ensure!(
+            Contributions::<T>::contains_key(project_key, &who),
+            Error::<T>::OnlyContributorsCanVote
+        );
+
+        let vote = Vote {
+            yay: (0_u32).into(),
+            nay: (0_u32).into(),
+            is_approved: false,
+        };
+        let end = <frame_system::Pallet<T>>::block_number() + T::NoConfidenceVotingPeriod::get();
+        NoConfidenceVotes::<T>::insert(project_key, vote);
+        NoConfidenceRoundEnds::<T>::insert(project_key, end);
+        NoConfidenceVotesEndingAt::<T>::append(end, project_key);
+        Self::deposit_event(Event::NoConfidenceRoundCreated(who.clone(), project_key));
+
+        Self::new_no_confidence_vote(who, project_key, true)
+    }
+
+    fn new_no_confidence_vote(
+        who: T::AccountId,
+        project_key: ProjectKey,
+        is_yay: bool,
+    ) -> DispatchResultWithPostInfo {
+        let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        ensure!(!project.cancelled, Error::<T>::ProjectCancelled);
+        let vote = Self::no_confidence_votes(project_key).ok_or(Error::<T>::NoActiveNoConfidenceRound)?;
+        ensure!(!vote.is_approved, Error::<T>::NoActiveNoConfidenceRound);
+        let now = <frame_system::Pallet<T>>::block_number();
+        let end = Self::no_confidence_round_ends(project_key).ok_or(Error::<T>::NoActiveNoConfidenceRound)?;
+        ensure!(end >= now, Error::<T>::NoActiveNoConfidenceRound);
+        let contribution_amount: BalanceOf<T> =
+            Self::contributions(project_key, &who).ok_or(Error::<T>::OnlyContributorsCanVote)?;
+
+        let user_vote_lookup_key = (who.clone(), project_key, end);
+        ensure!(
+            !UserNoConfidenceVotes::<T>::contains_key(&user_vote_lookup_key),
+            Error::<T>::VoteAlreadyExists
+        );
+        UserNoConfidenceVotes::<T>::insert(user_vote_lookup_key, is_yay);
+
+        let updated_vote = if is_yay {
+            Vote {
+                yay: vote.yay + contribution_amount,
+                nay: vote.nay,
+                is_approved: vote.is_approved,
+            }
+        } else {
+            Vote {
+                yay: vote.yay,
+                nay: vote.nay + contribution_amount,
+                is_approved: vote.is_approved,
+            }
+        };
+        Self::deposit_event(Event::NoConfidenceVoteComplete(who, project_key, is_yay, now));
+
+        // Refund the project as soon as enough of the contributions lost confidence
+        let total_contribution_amount: BalanceOf<T> =
+            Self::get_total_project_contributions(project_key)?;
+        if updated_vote.yay >= T::NoConfidenceThreshold::get().mul_ceil(total_contribution_amount) {
+            NoConfidenceVotes::<T>::insert(
+                project_key,
+                Vote {
+                    yay: updated_vote.yay,
+                    nay: updated_vote.nay,
+                    is_approved: true,
+                },
+            );
+            NoConfidenceRoundEnds::<T>::remove(project_key);
+            Self::do_refund(project_key)?;
+            Self::deposit_event(Event::NoConfidenceRoundFinalised(project_key));
+        } else {
+            NoConfidenceVotes::<T>::insert(project_key, updated_vote);
+        }
 
         Ok(().into())
     }
 
     pub fn do_refund(project_key: ProjectKey) -> DispatchResultWithPostInfo {
         let project = Projects::<T>::get(&project_key).ok_or(Error::<T>::ProjectDoesNotExist)?;
+        // The locked funds of a cancelled project have already been refunded
+        ensure!(!project.cancelled, Error::<T>::ProjectCancelled);
 
         //getting the locked milestone percentage - these are also milestones that have not been approved
         let mut refunded_funds: BalanceOf<T> = 0_u32.into();
@@ -1159,21 +4124,46 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        for contribution in project.contributions.iter() {
-            let who = contribution.account_id.clone();
+        let is_reserved = Self::holds_reserved_contributions(project_key, &project);
+        for (who, value) in Contributions::<T>::iter_prefix(project_key) {
             let refund_amount: BalanceOf<T> =
-                (contribution.value * locked_milestone_percentage.into()) / 100u32.into();
+                (value * locked_milestone_percentage.into()) / 100u32.into();
+
+            if is_reserved {
+                T::MultiCurrency::unreserve_named(
+                    &Self::contribution_reserve_id(project_key),
+                    project.currency_id,
+                    &who,
+                    refund_amount,
+                );
+            } else {
+                T::MultiCurrency::transfer(
+                    project.currency_id,
+                    &Self::project_account_id(project_key),
+                    &who,
+                    refund_amount,
+                )?;
+            }
+
+            refunded_funds += refund_amount;
+        }
 
+        // The matching funds of the locked milestones go back to the treasury
+        let matched_refund_amount: BalanceOf<T> = (ProjectMatchedFunds::<T>::get(project_key)
+            * locked_milestone_percentage.into())
+            / 100u32.into();
+        if matched_refund_amount > (0_u32).into() {
             T::MultiCurrency::transfer(
                 project.currency_id,
                 &Self::project_account_id(project_key),
-                &who,
-                refund_amount,
+                &T::TreasuryAccount::get(),
+                matched_refund_amount,
             )?;
-
-            refunded_funds += refund_amount;
+            refunded_funds += matched_refund_amount;
         }
 
+        Self::release_deposits(project_key, &project.initiator);
+
         // Update project cancellation status
         let updated_project = Project {
             name: project.name,
@@ -1181,7 +4171,6 @@ impl<T: Config> Pallet<T> {
             description: project.description,
             website: project.website,
             milestones: project.milestones,
-            contributions: project.contributions,
             required_funds: project.required_funds,
             currency_id: project.currency_id,
             withdrawn_funds: project.withdrawn_funds,
@@ -1209,14 +4198,16 @@ type MaxMileStoneKeys =  ConstU32<1000>;
 type MaxProposedMilestones = ConstU32<255>;
 type MaxDescriptionField = ConstU32<5000>;
 type MaxWhitelistPerProject = ConstU32<10000>;
+type MaxProjectRoles = ConstU32<3>;
+type MaxEvidenceFieldLen = ConstU32<128>;
 
 pub type RoundKey = u32;
 pub type ProjectKey = u32;
 pub type MilestoneKey = u32;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
-type ContributionOf<T> = Contribution<AccountIdOf<T>, BalanceOf<T>>;
 type RoundOf<T> = Round<<T as frame_system::Config>::BlockNumber>;
+type ProjectOf<T> = Project<AccountIdOf<T>, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
 // type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 
 // These are the bounded types which are suitable for handling user input due to their restriction of vector length.
@@ -1226,8 +4217,100 @@ type BoundedMilestoneKeys = BoundedVec<ProjectKey, MaxMileStoneKeys>;
 type BoundedStringField = BoundedVec<u8, MaxStringFieldLen>;
 type BoundedProposedMilestones = BoundedVec<ProposedMilestone, MaxProposedMilestones>;
 type BoundedDescriptionField = BoundedVec<u8, MaxDescriptionField>;
+type BoundedProjectRoles = BoundedVec<ProjectRole, MaxProjectRoles>;
+type BoundedEvidenceField = BoundedVec<u8, MaxEvidenceFieldLen>;
+
+/// What a member of a project team is allowed to do on behalf of the initiator.
+#[derive(Encode, Decode, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ProjectRole {
+    SubmitMilestones,
+    Withdraw,
+    /// Update the project and its whitelist.
+    Edit,
+}
+
+/// How much the voter's own milestone vote is multiplied in exchange for locking as much native
+/// funds, following the conviction model of `pallet_democracy`. Votes without conviction keep
+/// their full weight and lock nothing.
+#[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Conviction {
+    None,
+    Locked2x,
+    Locked3x,
+    Locked4x,
+    Locked5x,
+    Locked6x,
+}
+
+impl Conviction {
+    /// The multiplier of the voter's own weight.
+    pub fn multiplier(&self) -> u32 {
+        match self {
+            Conviction::None => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 3,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 5,
+            Conviction::Locked6x => 6,
+        }
+    }
+
+    /// The number of `ConvictionVoteLockingPeriod`s the funds stay locked for.
+    pub fn lock_periods(&self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked2x => 1,
+            Conviction::Locked3x => 2,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 8,
+            Conviction::Locked6x => 16,
+        }
+    }
+}
 
+/// A withdrawal which is paid out linearly over `period` blocks from `start`.
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct VestingSchedule<Balance, BlockNumber> {
+    pub total: Balance,
+    pub claimed: Balance,
+    pub start: BlockNumber,
+    pub period: BlockNumber,
+}
+
+/// How much funding a project needs and accepts.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FundingCaps<Balance> {
+    /// The share of `required_funds` which makes the project fundable once its round ended,
+    /// the pallet wide `FundingSoftCap` is used when `None`. Zero means all of `required_funds`.
+    pub soft_cap: Option<Perbill>,
+    /// Contributions beyond this amount are rejected.
+    pub hard_cap: Option<Balance>,
+    /// Whether the funds raised beyond `required_funds` go back to the contributors on approval.
+    pub return_overfunding: bool,
+}
+
+/// The funds sponsors put up to match the contributions of a round.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MatchingPool<Balance> {
+    currency_id: common_types::CurrencyId,
+    total: Balance,
+}
+
+/// Where the funds which were not withdrawn in time go.
+#[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, Debug, TypeInfo)]
+pub enum ExpiredFundsDestination {
+    /// Back to the contributors, pro rata to their contribution.
+    Contributors,
+    Treasury,
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum RoundType {
     ContributionRound,
     VotingRound,
@@ -1235,6 +4318,7 @@ pub enum RoundType {
 
 /// Round struct
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Round<BlockNumber> {
     start: BlockNumber,
     end: BlockNumber,
@@ -1282,10 +4366,13 @@ pub struct Contribution<AccountId, Balance> {
 pub struct ProposedMilestone {
     name: BoundedStringField,
     percentage_to_unlock: u32,
+    /// The block the milestone is due at, if any.
+    deadline: Option<u32>,
 }
 
 /// The contribution users made to a proposal project.
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Milestone {
     project_key: ProjectKey,
     milestone_key: MilestoneKey,
@@ -1296,21 +4383,42 @@ pub struct Milestone {
 
 /// The contribution users made to a proposal project.
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Vote<Balance> {
     yay: Balance,
     nay: Balance,
     is_approved: bool,
 }
 
+/// Where a milestone submission is in its vote, with the block the vote was finalised at.
+#[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MilestoneSubmissionStatus<BlockNumber> {
+    Voting,
+    Approved(BlockNumber),
+    Rejected(BlockNumber),
+}
+
+/// A submission of a milestone for a vote by the contributors.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MilestoneSubmission<Balance, BlockNumber> {
+    round_key: RoundKey,
+    submitted_at: BlockNumber,
+    status: MilestoneSubmissionStatus<BlockNumber>,
+    /// The final votes once the submission is no longer voting.
+    votes: Vote<Balance>,
+}
+
 /// Project struct
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Project<AccountId, Balance, BlockNumber> {
     name: Vec<u8>,
     logo: Vec<u8>,
     description: Vec<u8>,
     website: Vec<u8>,
     milestones: Vec<Milestone>,
-    contributions: Vec<Contribution<AccountId, Balance>>,
     currency_id: common_types::CurrencyId,
     required_funds: Balance,
     withdrawn_funds: Balance,
@@ -0,0 +1,125 @@
+use super::*;
+
+pub mod v1 {
+    use super::*;
+
+    /// The `Project` layout before contributions were moved into their own storage.
+    #[derive(Encode, Decode, Clone)]
+    pub(crate) struct ProjectV0<AccountId, Balance, BlockNumber> {
+        pub(crate) name: Vec<u8>,
+        pub(crate) logo: Vec<u8>,
+        pub(crate) description: Vec<u8>,
+        pub(crate) website: Vec<u8>,
+        pub(crate) milestones: Vec<Milestone>,
+        pub(crate) contributions: Vec<Contribution<AccountId, Balance>>,
+        pub(crate) currency_id: common_types::CurrencyId,
+        pub(crate) required_funds: Balance,
+        pub(crate) withdrawn_funds: Balance,
+        pub(crate) initiator: AccountId,
+        pub(crate) create_block_number: BlockNumber,
+        pub(crate) approved_for_funding: bool,
+        pub(crate) funding_threshold_met: bool,
+        pub(crate) cancelled: bool,
+    }
+
+    /// Move the contributions of every project into `Contributions` and
+    /// fill `ProjectContributionsTotal`.
+    pub fn migrate<T: Config>() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        if StorageVersion::get::<Pallet<T>>() != 0 {
+            return weight;
+        }
+
+        Projects::<T>::translate(
+            |project_key, project: ProjectV0<T::AccountId, BalanceOf<T>, T::BlockNumber>| {
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+
+                let mut total_contribution_amount: BalanceOf<T> = (0_u32).into();
+                for contribution in project.contributions.into_iter() {
+                    Contributions::<T>::mutate(project_key, &contribution.account_id, |value| {
+                        *value = Some(value.unwrap_or_else(|| (0_u32).into()) + contribution.value)
+                    });
+                    total_contribution_amount += contribution.value;
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                }
+                ProjectContributionsTotal::<T>::insert(project_key, total_contribution_amount);
+
+                Some(Project {
+                    name: project.name,
+                    logo: project.logo,
+                    description: project.description,
+                    website: project.website,
+                    milestones: project.milestones,
+                    required_funds: project.required_funds,
+                    currency_id: project.currency_id,
+                    withdrawn_funds: project.withdrawn_funds,
+                    initiator: project.initiator,
+                    create_block_number: project.create_block_number,
+                    approved_for_funding: project.approved_for_funding,
+                    funding_threshold_met: project.funding_threshold_met,
+                    cancelled: project.cancelled,
+                })
+            },
+        );
+
+        StorageVersion::new(1).put::<Pallet<T>>();
+        weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// Build the `ProjectRounds` index from the existing rounds, cancelled rounds excluded.
+    pub fn migrate<T: Config>() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        if StorageVersion::get::<Pallet<T>>() != 1 {
+            return weight;
+        }
+
+        // Rounds are not iterated in key order, sort them so each one supersedes the previous.
+        let mut project_rounds: Vec<(ProjectKey, RoundKey, RoundType)> = Vec::new();
+        for (round_key, round) in Rounds::<T>::iter() {
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            let round = match round {
+                Some(round) if !round.is_canceled => round,
+                _ => continue,
+            };
+            for project_key in round.project_keys.iter() {
+                project_rounds.push((*project_key, round_key, round.round_type));
+            }
+        }
+        project_rounds.sort_by_key(|(project_key, round_key, _)| (*project_key, *round_key));
+
+        for (project_key, round_key, round_type) in project_rounds {
+            if let Some(previous_round_key) = ProjectRounds::<T>::get(project_key, round_type) {
+                ProjectPreviousRounds::<T>::insert(project_key, round_key, previous_round_key);
+            }
+            ProjectRounds::<T>::insert(project_key, round_type, round_key);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        StorageVersion::new(2).put::<Pallet<T>>();
+        weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+pub mod v3 {
+    use super::*;
+
+    /// Fill `ProjectContributorCount` from the existing contributions.
+    pub fn migrate<T: Config>() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        if StorageVersion::get::<Pallet<T>>() != 2 {
+            return weight;
+        }
+
+        for (project_key, _, _) in Contributions::<T>::iter() {
+            ProjectContributorCount::<T>::mutate(project_key, |count| *count += 1);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+        }
+
+        StorageVersion::new(3).put::<Pallet<T>>();
+        weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+}
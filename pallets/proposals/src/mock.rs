@@ -7,7 +7,7 @@ use frame_support::{
     PalletId,
 };
 
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::{sr25519::Signature, Pair, Public, H256};
 
 use sp_std::{convert::{TryFrom, TryInto}, vec::Vec, str };
@@ -178,7 +178,7 @@ impl pallet_balances::Config for Test {
     type Event = Event;
     type ExistentialDeposit = ExistentialDeposit;
     type MaxLocks = ();
-    type MaxReserves = ();
+    type MaxReserves = MaxReserves;
     type ReserveIdentifier = [u8; 8];
     type WeightInfo = ();
 }
@@ -203,6 +203,22 @@ impl pallet_timestamp::Config for Test {
 parameter_types! {
     pub const TwoWeekBlockUnit: u32 = 100800u32;
     pub const ProposalsPalletId: PalletId = PalletId(*b"imbgrant");
+    pub TreasuryAccount: AccountId = PalletId(*b"py/trsry").into_account_truncating();
+    pub static ExpiredFunds: proposals::ExpiredFundsDestination =
+        proposals::ExpiredFundsDestination::Contributors;
+    pub static ProjectDepositPerItem: Balance = 0;
+    pub static ProjectDepositPerByte: Balance = 0;
+    pub const NoConfidenceThreshold: Perbill = Perbill::from_percent(75);
+    pub const NoConfidenceVotingPeriod: u64 = 10;
+    pub const MilestoneDeadlineExtension: u64 = 100;
+    pub const OverdueMilestoneMajority: Perbill = Perbill::from_percent(50);
+    pub const MilestoneResubmissionCooldown: u64 = 10;
+    pub const MaxMilestoneResubmissions: u32 = 1;
+    pub const ConvictionVoteLockingPeriod: u64 = 10;
+    pub const MaxConvictionLocks: u32 = 2;
+    pub const MaxDelegatorsPerDelegate: u32 = 2;
+    pub const MaxContributorsPerProject: u32 = 4;
+    pub const MaxTeamMembersPerProject: u32 = 2;
 }
 impl proposals::Config for Test {
     type Event = Event;
@@ -212,6 +228,26 @@ impl proposals::Config for Test {
     type MaxProposalsPerRound = ConstU32<4>;
     // Adding 2 weeks as th expiration time
     type MaxWithdrawalExpiration = TwoWeekBlockUnit;
+    type MaxMilestoneFinalisationsPerBlock = ConstU32<2>;
+    type ExpiredFundsDestination = ExpiredFunds;
+    type TreasuryAccount = TreasuryAccount;
+    type DepositPerItem = ProjectDepositPerItem;
+    type DepositPerByte = ProjectDepositPerByte;
+    type NoConfidenceThreshold = NoConfidenceThreshold;
+    type NoConfidenceVotingPeriod = NoConfidenceVotingPeriod;
+    type MilestoneDeadlineExtension = MilestoneDeadlineExtension;
+    type OverdueMilestoneMajority = OverdueMilestoneMajority;
+    type MilestoneResubmissionCooldown = MilestoneResubmissionCooldown;
+    type MaxMilestoneResubmissions = MaxMilestoneResubmissions;
+    type NativeCurrency = Balances;
+    type ConvictionVoteLockingPeriod = ConvictionVoteLockingPeriod;
+    type MaxConvictionLocks = MaxConvictionLocks;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
+    type MaxContributorsPerProject = MaxContributorsPerProject;
+    type MaxTeamMembersPerProject = MaxTeamMembersPerProject;
+    type AdminOrigin = EnsureSignedBy<AdminAccount, AccountId>;
+    type RoundOrigin = EnsureSignedBy<RoundAccount, AccountId>;
+    type RefundOrigin = EnsureSignedBy<RefundAccount, AccountId>;
 }
 
 parameter_types! {
@@ -225,6 +261,9 @@ parameter_types! {
 ord_parameter_types! {
     pub const One: u64 = 1;
     pub const Two: u64 = 2;
+    pub const AdminAccount: AccountId = sp_core::sr25519::Public([1u8; 32]);
+    pub const RoundAccount: AccountId = sp_core::sr25519::Public([2u8; 32]);
+    pub const RefundAccount: AccountId = sp_core::sr25519::Public([3u8; 32]);
 }
 
 impl pallet_identity::Config for Test {
@@ -3,7 +3,8 @@ use crate::mock::*;
 use crate::*;
 use common_types::CurrencyId;
 use frame_support::{
-    assert_noop, assert_ok, dispatch::DispatchErrorWithPostInfo, weights::PostDispatchInfo, bounded_vec
+    assert_noop, assert_ok, dispatch::DispatchErrorWithPostInfo, weights::PostDispatchInfo, bounded_vec,
+    traits::{NamedReservableCurrency, ReservableCurrency},
 };
 use sp_core::sr25519;
 use sp_std::vec::Vec;
@@ -26,6 +27,7 @@ fn create_a_test_project() {
             bounded_vec![ProposedMilestone {
                 name: bounded_vec![],
                 percentage_to_unlock: 100,
+                deadline: None,
             }],
             //funds required
             1000000u64,
@@ -51,7 +53,8 @@ fn create_a_test_project_with_less_than_100_percent() {
             b"https://imbue.network".to_vec().try_into().expect("input should be of decent length"),
             //milestone
             bounded_vec![ProposedMilestone {
-                name: bounded_vec![], percentage_to_unlock: 99
+                name: bounded_vec![], percentage_to_unlock: 99,
+                deadline: None
             }],
             //funds required
             1000000u64,
@@ -83,7 +86,8 @@ fn create_a_test_project_with_no_name() {
             b"https://imbue.network".to_vec().try_into().expect("input should be of decent length"),
             //milestone
             bounded_vec![ProposedMilestone {
-                name: bounded_vec![], percentage_to_unlock: 99
+                name: bounded_vec![], percentage_to_unlock: 99,
+                deadline: None
             }],
             //funds required
             1000000u64,
@@ -116,7 +120,8 @@ fn create_a_test_project_with_no_data() {
                 //milestone
                 bounded_vec![ProposedMilestone {
                     name: bounded_vec![],
-                    percentage_to_unlock: 99
+                    percentage_to_unlock: 99,
+                    deadline: None
                 }],
                 //funds required
                 1000000u64,
@@ -206,7 +211,7 @@ fn create_a_test_project_and_schedule_round() {
         create_project(alice);
 
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number(),
             System::block_number() + 1,
             //Project key starts with 0 for the first project submitted to the chain
@@ -225,7 +230,7 @@ fn schedule_round_invalid_project_key() {
 
         assert_noop!(
             Proposals::schedule_round(
-                Origin::root(),
+                round_origin(),
                 System::block_number(),
                 System::block_number() + 1,
                 //Project key starts with 0 for the first project submitted to the chain
@@ -251,7 +256,7 @@ fn schedule_round_invalid_end_block_no() {
 
         assert_noop!(
             Proposals::schedule_round(
-                Origin::root(),
+                round_origin(),
                 System::block_number() + 6000,
                 System::block_number() + 3000,
                 //Project key starts with 0 for the first project submitted to the chain
@@ -277,7 +282,7 @@ fn cancel_round_no_active_round() {
 
         assert_noop!(
             Proposals::schedule_round(
-                Origin::root(),
+                round_origin(),
                 System::block_number() + 6000,
                 System::block_number() + 3000,
                 //Project key starts with 0 for the first project submitted to the chain
@@ -294,7 +299,7 @@ fn cancel_round_no_active_round() {
         );
 
         assert_noop!(
-            Proposals::cancel_round(Origin::root(), 0),
+            Proposals::cancel_round(round_origin(), 0),
             DispatchErrorWithPostInfo {
                 post_info: PostDispatchInfo {
                     actual_weight: None,
@@ -315,7 +320,7 @@ fn cancel_round() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
         //schedule_round extrinsic
         assert_ok!(Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 2,
             project_keys.clone(),
@@ -336,7 +341,7 @@ fn cancel_round() {
 
         //cancel_round extrinsic
         assert_ok!(<proposals::Pallet<Test>>::cancel_round(
-            Origin::root(),
+            round_origin(),
             round_index
         ));
 
@@ -364,28 +369,64 @@ fn test_canceling_started_round() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
             RoundType::ContributionRound
         ));
 
+        assert_ok!(<proposals::Pallet<Test>>::cancel_round(round_origin(), 0));
+        assert!(Proposals::rounds(0).unwrap().is_canceled);
+        assert!(RoundRefundQueue::<Test>::contains_key((0, 0)));
+
+        let latest_event = <frame_system::Pallet<Test>>::events()
+            .pop()
+            .expect("Expected at least one EventRecord to be found")
+            .event;
+        assert_eq!(
+            latest_event,
+            mock::Event::from(proposals::Event::RoundCancelled(0))
+        );
+    });
+}
+
+#[test]
+fn test_canceling_ended_round() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 100000000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project(alice);
+
+        let project_keys: BoundedProjectKeys = bounded_vec![0];
+
+        assert_ok!(<proposals::Pallet<Test>>::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            project_keys,
+            RoundType::ContributionRound
+        ));
+
+        run_to_block(System::block_number() + 2);
         assert_noop!(
-            <proposals::Pallet<Test>>::cancel_round(Origin::root(), 0),
+            <proposals::Pallet<Test>>::cancel_round(round_origin(), 0),
             DispatchErrorWithPostInfo {
                 post_info: PostDispatchInfo {
                     actual_weight: None,
                     pays_fee: Pays::Yes,
                 },
-                error: Error::<Test>::RoundStarted.into(),
+                error: Error::<Test>::RoundEnded.into(),
             }
         );
     });
 }
 
 #[test]
-//only user with root privilege can cancel the round
+//only the round origin can cancel the round
 fn test_canceling_round_without_root_privilege() {
     let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
     let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
@@ -398,7 +439,7 @@ fn test_canceling_round_without_root_privilege() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -431,7 +472,7 @@ fn create_a_test_project_and_schedule_round_and_contribute() {
 
         //schedule_round extrinsic
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 10,
             //Project key starts with 0 for the first project submitted to the chain
@@ -488,7 +529,7 @@ fn create_a_test_project_and_schedule_round_and_add_whitelist_with_cap_and_contr
 
         //schedule_round extrinsic
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 10,
             //Project key starts with 0 for the first project submitted to the chain
@@ -546,7 +587,7 @@ fn create_a_test_project_and_schedule_round_and_add_whitelist_with_unlimited_cap
 
         //schedule_round extrinsic
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 10,
             //Project key starts with 0 for the first project submitted to the chain
@@ -604,7 +645,7 @@ fn create_a_test_project_and_schedule_round_and_add_whitelist_and_contribute_ove
 
         //schedule_round extrinsic
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 10,
             //Project key starts with 0 for the first project submitted to the chain
@@ -647,7 +688,7 @@ fn create_a_test_project_and_schedule_round_and_contribute_and_approve() {
 
         //schedule_round extrinsic
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 10,
             //Project key starts with 0 for the first project submitted to the chain
@@ -665,7 +706,7 @@ fn create_a_test_project_and_schedule_round_and_contribute_and_approve() {
         Proposals::contribute(Origin::signed(alice), project_key, contribution_amount).unwrap();
 
         //approve project
-        Proposals::approve(Origin::root(), 0, None).unwrap();
+        Proposals::approve(admin_origin(), 0, None).unwrap();
 
         //approve event
         let exp_approvedproject_event = <frame_system::Pallet<Test>>::events()
@@ -693,7 +734,7 @@ fn create_a_test_project_and_schedule_round_and_contribute_and_approvefail() {
 
         //schedule_round extrinsic
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 10,
             //Project key starts with 0 for the first project submitted to the chain
@@ -712,7 +753,7 @@ fn create_a_test_project_and_schedule_round_and_contribute_and_approvefail() {
 
         assert_noop!(
             //approve project
-            Proposals::approve(Origin::root(), project_key, None),
+            Proposals::approve(admin_origin(), project_key, None),
             DispatchErrorWithPostInfo {
                 post_info: PostDispatchInfo {
                     actual_weight: None,
@@ -738,7 +779,7 @@ fn test_submit_milestone() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -757,12 +798,13 @@ fn test_submit_milestone() {
 
         run_to_block(3);
 
-        assert_ok!(Proposals::approve(Origin::root(), project_index, None));
+        assert_ok!(Proposals::approve(admin_origin(), project_index, None));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            0
+            0,
+            milestone_evidence()
         ));
 
         let latest_event = <frame_system::Pallet<Test>>::events()
@@ -791,7 +833,7 @@ fn test_submit_milestone_without_approval() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -811,7 +853,7 @@ fn test_submit_milestone_without_approval() {
         run_to_block(3);
 
         assert_noop!(
-            Proposals::submit_milestone(Origin::signed(alice), project_index, 0),
+            Proposals::submit_milestone(Origin::signed(alice), project_index, 0, milestone_evidence()),
             DispatchErrorWithPostInfo {
                 post_info: PostDispatchInfo {
                     actual_weight: None,
@@ -837,7 +879,7 @@ fn test_voting_on_a_milestone() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -856,12 +898,13 @@ fn test_voting_on_a_milestone() {
 
         run_to_block(3);
 
-        assert_ok!(Proposals::approve(Origin::root(), project_index, None));
+        assert_ok!(Proposals::approve(admin_origin(), project_index, None));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            0
+            0,
+            milestone_evidence()
         ));
 
         run_to_block(5);
@@ -898,14 +941,14 @@ fn test_voting_on_a_canceled_round() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 2,
             project_keys,
             RoundType::ContributionRound
         ));
 
-        assert_ok!(<proposals::Pallet<Test>>::cancel_round(Origin::root(), 0));
+        assert_ok!(<proposals::Pallet<Test>>::cancel_round(round_origin(), 0));
 
         run_to_block(5);
         assert_noop!(
@@ -941,15 +984,18 @@ fn test_finalize_a_milestone_without_voting() {
     let milestone1: ProposedMilestone = ProposedMilestone {
         name: b"milestone 1".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 20,
+        deadline: None,
     };
     let milestone2: ProposedMilestone = ProposedMilestone {
         name: b"milestone 2".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 30,
+        deadline: None,
     };
 
     let milestone3: ProposedMilestone = ProposedMilestone {
         name: b"milestone 3".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 50,
+        deadline: None,
     };
     proposed_milestones.push(milestone1);
     proposed_milestones.push(milestone2);
@@ -963,7 +1009,7 @@ fn test_finalize_a_milestone_without_voting() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -984,7 +1030,7 @@ fn test_finalize_a_milestone_without_voting() {
         run_to_block(3);
 
         assert_ok!(Proposals::approve(
-            Origin::root(),
+            admin_origin(),
             project_index,
             Some(milestone_index)
         ));
@@ -992,13 +1038,15 @@ fn test_finalize_a_milestone_without_voting() {
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            0
+            0,
+            milestone_evidence()
         ));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            1
+            1,
+            milestone_evidence()
         ));
 
         run_to_block(5);
@@ -1042,15 +1090,18 @@ fn test_project_initiator_can_withdraw_only_the_percentage_milestone_completed()
     let milestone1: ProposedMilestone = ProposedMilestone {
         name: b"milestone 1".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 20,
+        deadline: None,
     };
     let milestone2: ProposedMilestone = ProposedMilestone {
         name: b"milestone 2".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 30,
+        deadline: None,
     };
 
     let milestone3: ProposedMilestone = ProposedMilestone {
         name: b"milestone 3".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 50,
+        deadline: None,
     };
     proposed_milestones.push(milestone1);
     proposed_milestones.push(milestone2);
@@ -1066,7 +1117,7 @@ fn test_project_initiator_can_withdraw_only_the_percentage_milestone_completed()
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -1092,18 +1143,20 @@ fn test_project_initiator_can_withdraw_only_the_percentage_milestone_completed()
 
         run_to_block(3);
 
-        assert_ok!(Proposals::approve(Origin::root(), project_index, None));
+        assert_ok!(Proposals::approve(admin_origin(), project_index, None));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            0
+            0,
+            milestone_evidence()
         ));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            1
+            1,
+            milestone_evidence()
         ));
 
         run_to_block(5);
@@ -1152,7 +1205,8 @@ fn test_project_initiator_can_withdraw_only_the_percentage_milestone_completed()
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            2
+            2,
+            milestone_evidence()
         ));
         run_to_block(10);
         //Bob voting on the submitted milestone
@@ -1206,15 +1260,18 @@ fn test_project_initiator_can_withdraw_only_the_percentage_after_force_milestone
     let milestone1: ProposedMilestone = ProposedMilestone {
         name: b"milestone 1".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 20,
+        deadline: None,
     };
     let milestone2: ProposedMilestone = ProposedMilestone {
         name: b"milestone 2".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 30,
+        deadline: None,
     };
 
     let milestone3: ProposedMilestone = ProposedMilestone {
         name: b"milestone 3".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 50,
+        deadline: None,
     };
     proposed_milestones.push(milestone1);
     proposed_milestones.push(milestone2);
@@ -1230,7 +1287,7 @@ fn test_project_initiator_can_withdraw_only_the_percentage_after_force_milestone
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -1257,7 +1314,7 @@ fn test_project_initiator_can_withdraw_only_the_percentage_after_force_milestone
         run_to_block(3);
 
         assert_ok!(Proposals::approve(
-            Origin::root(),
+            admin_origin(),
             project_index,
             Some(milestone_index)
         ));
@@ -1314,7 +1371,7 @@ fn test_withdraw_upon_project_approval_and_finalised_voting() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -1333,12 +1390,13 @@ fn test_withdraw_upon_project_approval_and_finalised_voting() {
 
         run_to_block(3);
 
-        assert_ok!(Proposals::approve(Origin::root(), project_index, None));
+        assert_ok!(Proposals::approve(admin_origin(), project_index, None));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            0
+            0,
+            milestone_evidence()
         ));
 
         run_to_block(5);
@@ -1416,10 +1474,12 @@ fn submit_multiple_milestones() {
     let milestone1: ProposedMilestone = ProposedMilestone {
         name: b"milestone 1".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 50,
+        deadline: None,
     };
     let milestone2: ProposedMilestone = ProposedMilestone {
         name: b"milestone 2".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 50,
+        deadline: None,
     };
     proposed_milestones.push(milestone1);
     proposed_milestones.push(milestone2);
@@ -1435,7 +1495,7 @@ fn submit_multiple_milestones() {
         let milestone_index_2 = 1;
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -1455,12 +1515,13 @@ fn submit_multiple_milestones() {
 
         run_to_block(3);
 
-        assert_ok!(Proposals::approve(Origin::root(), project_index, None));
+        assert_ok!(Proposals::approve(admin_origin(), project_index, None));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            milestone_index_1
+            milestone_index_1,
+            milestone_evidence()
         ));
 
         let voting_round_event_1 = <frame_system::Pallet<Test>>::events()
@@ -1477,7 +1538,8 @@ fn submit_multiple_milestones() {
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            milestone_index_2
+            milestone_index_2,
+            milestone_evidence()
         ));
 
         let voting_round_event_2 = <frame_system::Pallet<Test>>::events()
@@ -1504,7 +1566,7 @@ fn create_a_test_project_and_schedule_round_and_contribute_and_refund() {
 
         //schedule_round extrinsic
         Proposals::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() + 1,
             System::block_number() + 10,
             //Project key starts with 0 for the first project submitted to the chain
@@ -1531,7 +1593,7 @@ fn create_a_test_project_and_schedule_round_and_contribute_and_refund() {
         assert_eq!(alice_balance_post_contribute,Balances::free_balance(&alice));
 
         Proposals::refund(
-            Origin::root(),
+            refund_origin(),
             project_key
         )
         .unwrap();
@@ -1567,15 +1629,18 @@ fn withdraw_percentage_milestone_completed_refund_locked_milestone() {
     let milestone1: ProposedMilestone = ProposedMilestone {
         name: b"milestone 1".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 20,
+        deadline: None,
     };
     let milestone2: ProposedMilestone = ProposedMilestone {
         name: b"milestone 2".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 30,
+        deadline: None,
     };
 
     let milestone3: ProposedMilestone = ProposedMilestone {
         name: b"milestone 3".to_vec().try_into().expect("input should be of decent length"),
         percentage_to_unlock: 50,
+        deadline: None,
     };
     proposed_milestones.push(milestone1);
     proposed_milestones.push(milestone2);
@@ -1591,7 +1656,7 @@ fn withdraw_percentage_milestone_completed_refund_locked_milestone() {
         let project_keys: BoundedProjectKeys = bounded_vec![0];
 
         assert_ok!(<proposals::Pallet<Test>>::schedule_round(
-            Origin::root(),
+            round_origin(),
             System::block_number() - 1,
             System::block_number() + 1,
             project_keys,
@@ -1617,7 +1682,7 @@ fn withdraw_percentage_milestone_completed_refund_locked_milestone() {
         run_to_block(3);
 
         assert_ok!(Proposals::approve(
-            Origin::root(),
+            admin_origin(),
             project_index,
             Some(milestone_index)
         ));
@@ -1625,13 +1690,15 @@ fn withdraw_percentage_milestone_completed_refund_locked_milestone() {
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            0
+            0,
+            milestone_evidence()
         ));
 
         assert_ok!(Proposals::submit_milestone(
             Origin::signed(alice),
             project_index,
-            1
+            1,
+            milestone_evidence()
         ));
 
         run_to_block(5);
@@ -1677,7 +1744,7 @@ fn withdraw_percentage_milestone_completed_refund_locked_milestone() {
         assert_eq!(contributor_balance_pre_refund,Balances::free_balance(&charlie));
 
         Proposals::refund(
-            Origin::root(),
+            refund_origin(),
             project_index
         )
         .unwrap();
@@ -1704,101 +1771,4016 @@ fn withdraw_percentage_milestone_completed_refund_locked_milestone() {
 }
 
 #[test]
-fn test_schedule_round_fails_gracefully_with_empty_vec() {
+fn cancel_round_does_not_refund_contributions_of_other_rounds() {
     let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let additional_amount = 10_000u64;
+    let contribution_amount = 2_000u64;
+
     ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        let _ = Currencies::deposit(CurrencyId::Native, &charlie, additional_amount);
         create_project(alice);
 
-        assert_noop!(Proposals::schedule_round(
-            Origin::root(),
-            System::block_number(),
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 2,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, contribution_amount));
+        run_to_block(System::block_number() + 3);
+
+        // Schedule another round for the same project and cancel it
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(charlie), 0, contribution_amount));
+        assert_ok!(Proposals::cancel_round(round_origin(), 1));
+        assert!(RoundRefundQueue::<Test>::contains_key((1, 0)));
+
+        Proposals::on_idle(System::block_number(), Weight::max_value());
+        assert_eq!(RoundRefundQueue::<Test>::iter_keys().count(), 0);
+        assert_eq!(Balances::free_balance(&bob), additional_amount - contribution_amount);
+        assert_eq!(Balances::free_balance(&charlie), additional_amount);
+        assert_eq!(Proposals::contributions(0, &bob), Some(contribution_amount));
+        assert_eq!(
+            Proposals::get_total_project_contributions(0).unwrap(),
+            contribution_amount
+        );
+    });
+}
+
+#[test]
+fn projects_cannot_be_scheduled_in_overlapping_rounds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000u64);
+        create_project(alice);
+        create_project(alice);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_noop!(
+            Proposals::schedule_round(
+                round_origin(),
+                System::block_number() + 5,
+                System::block_number() + 20,
+                bounded_vec![0],
+                RoundType::ContributionRound
+            ),
+            Error::<Test>::ProjectAlreadyInRound
+        );
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 5,
+            System::block_number() + 20,
+            bounded_vec![1],
+            RoundType::ContributionRound
+        ));
+        assert_noop!(
+            Proposals::update_round_projects(round_origin(), 1, bounded_vec![0], bounded_vec![]),
+            Error::<Test>::ProjectAlreadyInRound
+        );
+
+        // The open round is still the one contributions go to
+        assert_eq!(Proposals::project_rounds(0, RoundType::ContributionRound), Some(0));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000u64));
+        assert_eq!(Proposals::round_contributions((0, 0), &bob), 1_000u64);
+    });
+}
+
+#[test]
+fn cancelling_the_latest_round_falls_back_to_the_previous_one() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 2,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+        run_to_block(System::block_number() + 3);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
             System::block_number() + 1,
-            // Empty keys is the test.
-            bounded_vec![],
+            System::block_number() + 10,
+            bounded_vec![0],
             RoundType::ContributionRound
-        ), Error::<Test>::LengthMustExceedZero);
+        ));
+        assert_eq!(Proposals::project_rounds(0, RoundType::ContributionRound), Some(1));
+        assert_eq!(Proposals::project_previous_rounds(0, 1), Some(0));
+
+        assert_ok!(Proposals::cancel_round(round_origin(), 1));
+        assert_eq!(Proposals::project_rounds(0, RoundType::ContributionRound), Some(0));
+        assert_eq!(Proposals::project_previous_rounds(0, 1), None);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert!(Proposals::projects(0).unwrap().funding_threshold_met);
     });
 }
 
-//common helper methods
-fn create_project(alice: AccountId) {
-    assert_ok!(Proposals::create_project(
-        Origin::signed(alice),
-        //project name
-        b"Farmer's Project Sudan"
-        .to_vec()
-        .try_into()
-        .expect("test bytes should be of decent length;"),
-        //project logo
-        b"Imbue Logo"
-        .to_vec()
-        .try_into()
-        .expect("input should be of decent length"),
-        //project description
-        
-        b"This project is aimed at providing decentralised funding for a farming project."
-        .to_vec()
-        .try_into()
-        .expect("test bytes should be of decent length;"),
-        
-            //website
-        b"https://farmers.network"
-        .to_vec()
-        .try_into()
-        .expect("test bytes should be of decent length;"),
+#[test]
+fn cancel_round_refunds_round_contributions_in_on_idle() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let additional_amount = 10_000u64;
+    let contribution_amount = 2_000u64;
 
-        //milestone
-        bounded_vec![ProposedMilestone {
-            name: bounded_vec![],
-            percentage_to_unlock: 100
-        }],
-        //funds required
-        1000000u64,
-        CurrencyId::Native
-    ));
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        let _ = Currencies::deposit(CurrencyId::Native, &charlie, additional_amount);
+        create_project(alice);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, contribution_amount));
+        assert_ok!(Proposals::contribute(Origin::signed(charlie), 0, contribution_amount));
+        assert_eq!(Proposals::round_contributions((0, 0), &bob), contribution_amount);
+        assert_ok!(Proposals::cancel_round(round_origin(), 0));
+
+        // Only enough weight for a single refund
+        Proposals::on_idle(
+            System::block_number(),
+            <() as WeightInfo>::refund_contribution(),
+        );
+        assert!(RoundRefundQueue::<Test>::contains_key((0, 0)));
+        assert_eq!(RoundContributions::<Test>::iter_prefix((0, 0)).count(), 1);
+        assert_eq!(
+            Balances::free_balance(&bob) + Balances::free_balance(&charlie),
+            2 * additional_amount - contribution_amount
+        );
+
+        Proposals::on_idle(
+            System::block_number(),
+            <() as WeightInfo>::refund_contribution(),
+        );
+        assert_eq!(Balances::free_balance(&bob), additional_amount);
+        assert_eq!(Balances::free_balance(&charlie), additional_amount);
+        assert_eq!(RoundRefundQueue::<Test>::iter_keys().count(), 0);
+        assert_eq!(Proposals::contributions(0, &bob), None);
+        assert_eq!(Proposals::get_total_project_contributions(0).unwrap(), 0);
+    });
 }
 
-fn create_project_multiple_milestones(
-    alice: AccountId,
-    proposed_milestones: Vec<ProposedMilestone>,
-) {
-    assert_ok!(Proposals::create_project(
-        Origin::signed(alice),
-        //project name
-        b"Farmer's Project Sudan"
-        .to_vec()
-        .try_into()
-        .expect("input should be of decent length"),
-        //project logo
-        b"Imbue Logo"
-        .to_vec()
-        .try_into()
-        .expect("input should be of decent length"),
-        //project description
-        
-        b"This project is aimed at providing decentralised funding for a farming project."
-        .to_vec()
-        .try_into()
-        .expect("input should be of decent length"),
-        //website
-        
-        b"https://farmers.network"
-        .to_vec()
-        .try_into()
-        .expect("input should be of decent length"),
-        
-        //milestone
-        proposed_milestones.try_into().expect("proposed milestones are too long"),
-        //funds required
-        1000000u64,
-        CurrencyId::Native
-    ));
+#[test]
+fn cancel_round_skips_refunds_of_funded_projects() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 1_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project(alice);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, additional_amount));
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert_ok!(Proposals::cancel_round(round_origin(), 0));
+
+        Proposals::on_idle(System::block_number(), Weight::max_value());
+        assert_eq!(RoundRefundQueue::<Test>::iter_keys().count(), 0);
+        assert_eq!(RoundContributions::<Test>::iter_prefix((0, 0)).count(), 0);
+        assert_eq!(Balances::free_balance(&bob), 0);
+        assert_eq!(Proposals::contributions(0, &bob), Some(additional_amount));
+    });
 }
 
-fn deposit_initial_balance(alice: &AccountId, bob: &AccountId, additional_amount: u64) {
-    let _ = Currencies::deposit(CurrencyId::Native, &alice, additional_amount);
+#[test]
+fn migration_v1_moves_contributions_out_of_projects() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
 
-    let _ = Currencies::deposit(CurrencyId::Native, &bob, additional_amount);
+    ExtBuilder.build().execute_with(|| {
+        let old_project = migration::v1::ProjectV0 {
+            name: b"Farmer's Project Sudan".to_vec(),
+            logo: b"Imbue Logo".to_vec(),
+            description: b"Decentralised funding for a farming project.".to_vec(),
+            website: b"https://farmers.network".to_vec(),
+            milestones: vec![Milestone {
+                project_key: 0,
+                milestone_key: 0,
+                name: Vec::new(),
+                percentage_to_unlock: 100,
+                is_approved: false,
+            }],
+            contributions: vec![
+                Contribution {
+                    account_id: bob,
+                    value: 300u64,
+                },
+                Contribution {
+                    account_id: charlie,
+                    value: 700u64,
+                },
+            ],
+            currency_id: CurrencyId::Native,
+            required_funds: 1000u64,
+            withdrawn_funds: 0u64,
+            initiator: alice,
+            create_block_number: 1u64,
+            approved_for_funding: true,
+            funding_threshold_met: false,
+            cancelled: false,
+        };
+        frame_support::storage::unhashed::put(
+            &proposals::Projects::<Test>::hashed_key_for(0),
+            &old_project,
+        );
+
+        migration::v1::migrate::<Test>();
+
+        assert!(Proposals::projects(0).is_some());
+        assert_eq!(Proposals::contributions(0, bob), Some(300u64));
+        assert_eq!(Proposals::contributions(0, charlie), Some(700u64));
+        assert_eq!(Proposals::get_total_project_contributions(0).unwrap(), 1000u64);
+        assert_eq!(StorageVersion::get::<Proposals>(), 1);
+    });
+}
+
+#[test]
+fn milestone_approval_threshold_must_be_below_one() {
+    ExtBuilder.build().execute_with(|| {
+        assert_noop!(
+            Proposals::set_milestone_approval_threshold(admin_origin(), Perbill::one()),
+            Error::<Test>::ParamLimitExceed
+        );
+    });
+}
+
+#[test]
+fn milestone_voting_can_be_finalised_once_the_quorum_is_reached() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::set_milestone_voting_window(admin_origin(), 5));
+        assert_ok!(Proposals::set_milestone_voting_quorum(admin_origin(), Perbill::from_percent(40)));
+        submit_first_milestone(alice);
+
+        // Bob holds 40% of the contributions, so the quorum is reached
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::MilestoneRejected(alice, 0, 0, 4))
+        );
+        assert!(!Proposals::milestone_votes((0, 0)).unwrap().is_approved);
+    });
+}
+
+#[test]
+fn milestone_voting_cannot_be_finalised_without_quorum_while_the_window_is_open() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::set_milestone_voting_window(admin_origin(), 5));
+        assert_ok!(Proposals::set_milestone_voting_quorum(admin_origin(), Perbill::one()));
+        submit_first_milestone(alice);
+
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(charlie), 0, 0, true));
+        assert_noop!(
+            Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0),
+            Error::<Test>::MilestoneVotingNotComplete
+        );
+    });
+}
+
+#[test]
+fn milestone_voting_is_decided_by_the_cast_votes_once_the_window_ends() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::set_milestone_voting_window(admin_origin(), 5));
+        assert_ok!(Proposals::set_milestone_voting_quorum(admin_origin(), Perbill::one()));
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(5);
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(charlie), 0, 0, true));
+
+        run_to_block(10);
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::MilestoneApproved(alice, 0, 0, 10))
+        );
+        assert!(Proposals::milestone_votes((0, 0)).unwrap().is_approved);
+    });
+}
+
+#[test]
+fn milestone_votes_are_finalised_when_the_voting_window_ends() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+    let contribution_amount = 1_000_000u64;
+
+    let proposed_milestones: Vec<ProposedMilestone> = vec![30, 30, 40]
+        .into_iter()
+        .map(|percentage_to_unlock| ProposedMilestone {
+            name: b"milestone".to_vec().try_into().expect("input should be of decent length"),
+            percentage_to_unlock,
+            deadline: None,
+        })
+        .collect();
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project_multiple_milestones(alice, proposed_milestones);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, contribution_amount));
+
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert_ok!(Proposals::set_milestone_voting_window(admin_origin(), 5));
+        for milestone_key in 0..3 {
+            assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, milestone_key, milestone_evidence()));
+        }
+        assert_eq!(Proposals::milestone_votes_ending_at(8), vec![(0, 0), (0, 1), (0, 2)]);
+
+        run_to_block(4);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, true));
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 1, true));
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 2, false));
+
+        // Only two votes are finalised per block, the last one is carried over.
+        run_to_block(9);
+        assert!(Proposals::milestone_votes((0, 0)).unwrap().is_approved);
+        assert!(Proposals::milestone_votes((0, 1)).unwrap().is_approved);
+        assert!(Proposals::milestone_votes_ending_at(8).is_empty());
+        assert_eq!(Proposals::milestone_votes_ending_at(9), vec![(0, 2)]);
+
+        run_to_block(10);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::MilestoneRejected(alice, 0, 2, 10))
+        );
+        assert!(!Proposals::milestone_votes((0, 2)).unwrap().is_approved);
+        assert!(Proposals::milestone_votes_ending_at(9).is_empty());
+        assert_eq!(
+            Proposals::projects(0)
+                .unwrap()
+                .milestones
+                .iter()
+                .filter(|milestone| milestone.is_approved)
+                .count(),
+            2
+        );
+    });
+}
+
+#[test]
+fn withdrawal_expiration_is_bounded() {
+    ExtBuilder.build().execute_with(|| {
+        assert_noop!(
+            Proposals::set_withdrawal_expiration(admin_origin(), TwoWeekBlockUnit::get() + 1),
+            Error::<Test>::WithdrawalExpirationExceed
+        );
+    });
+}
+
+#[test]
+fn expired_withdrawals_are_returned_to_the_contributors() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[50, 50]),
+            || {
+                assert_ok!(Proposals::set_withdrawal_expiration(admin_origin(), 5));
+            },
+            1,
+            &[(bob, 600_000u64), (charlie, 400_000u64)],
+        );
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_eq!(Proposals::project_withdrawal_expiry(0), Some(8));
+
+        // The initiator did not withdraw in time
+        run_to_block(9);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ExpiredFundsReclaimed(
+                0,
+                500_000u64,
+                ExpiredFundsDestination::Contributors
+            ))
+        );
+        assert_eq!(Balances::free_balance(&bob), additional_amount - 300_000u64);
+        assert_eq!(Balances::free_balance(&charlie), additional_amount - 200_000u64);
+        assert_noop!(
+            Proposals::withdraw(Origin::signed(alice), 0),
+            Error::<Test>::NoAvailableFundsToWithdraw
+        );
+    });
+}
+
+#[test]
+fn expired_withdrawals_can_go_to_the_treasury() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        ExpiredFunds::set(ExpiredFundsDestination::Treasury);
+        fund_project(
+            alice,
+            milestones(&[50, 50]),
+            || {
+                assert_ok!(Proposals::set_withdrawal_expiration(admin_origin(), 5));
+            },
+            1,
+            &[(bob, 600_000u64), (charlie, 400_000u64)],
+        );
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        run_to_block(9);
+        assert_eq!(Balances::free_balance(&TreasuryAccount::get()), 500_000u64);
+        assert_eq!(Proposals::get_withdrawable_funds(0).unwrap(), 0);
+        ExpiredFunds::set(ExpiredFundsDestination::Contributors);
+    });
+}
+
+#[test]
+fn failed_reclaims_of_expired_withdrawals_are_retried() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project(alice);
+        assert_ok!(Proposals::set_withdrawal_expiration(admin_origin(), 5));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_eq!(Proposals::project_withdrawal_expiry(0), Some(8));
+
+        // The project account cannot pay out, the reclaim is retried in the next block
+        let project_account = Proposals::project_account_id(0);
+        assert_ok!(Currencies::withdraw(CurrencyId::Native, &project_account, 1_000_000u64));
+        run_to_block(9);
+        assert_eq!(Proposals::project_withdrawal_expiry(0), Some(9));
+        assert_eq!(Proposals::withdrawals_expiring_at(9), vec![0]);
+
+        assert_ok!(Currencies::deposit(CurrencyId::Native, &project_account, 1_000_000u64));
+        run_to_block(10);
+        assert_eq!(Proposals::project_withdrawal_expiry(0), None);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ExpiredFundsReclaimed(
+                0,
+                1_000_000u64,
+                ExpiredFundsDestination::Contributors
+            ))
+        );
+        assert_eq!(Balances::free_balance(&bob), additional_amount);
+    });
+}
+
+#[test]
+fn schedule_round_is_bounded_by_max_proposals_per_round() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        for _ in 0..5 {
+            create_project(alice);
+        }
+        assert_noop!(
+            Proposals::schedule_round(
+                round_origin(),
+                System::block_number() + 1,
+                System::block_number() + 10,
+                bounded_vec![0, 1, 2, 3, 4],
+                RoundType::ContributionRound
+            ),
+            Error::<Test>::ProposalAmountExceed
+        );
+    });
+}
+
+#[test]
+fn max_proposal_count_per_round_must_be_within_max_proposals_per_round() {
+    ExtBuilder.build().execute_with(|| {
+        assert_noop!(
+            Proposals::set_max_proposal_count_per_round(admin_origin(), 0),
+            Error::<Test>::ParamLimitExceed
+        );
+        assert_noop!(
+            Proposals::set_max_proposal_count_per_round(admin_origin(), 5),
+            Error::<Test>::ParamLimitExceed
+        );
+    });
+}
+
+#[test]
+fn schedule_round_respects_max_proposal_count_per_round() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        for _ in 0..3 {
+            create_project(alice);
+        }
+        assert_ok!(Proposals::set_max_proposal_count_per_round(admin_origin(), 2));
+
+        assert_noop!(
+            Proposals::schedule_round(
+                round_origin(),
+                System::block_number() + 1,
+                System::block_number() + 10,
+                bounded_vec![0, 1, 2],
+                RoundType::ContributionRound
+            ),
+            Error::<Test>::ProposalAmountExceed
+        );
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+    });
+}
+
+#[test]
+fn only_projects_in_a_round_can_be_removed_from_it() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        for _ in 0..3 {
+            create_project(alice);
+        }
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_noop!(
+            Proposals::update_round_projects(round_origin(), 0, bounded_vec![], bounded_vec![2]),
+            Error::<Test>::ProjectNotInRound
+        );
+    });
+}
+
+#[test]
+fn rounds_cannot_be_left_without_projects() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        for _ in 0..3 {
+            create_project(alice);
+        }
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_noop!(
+            Proposals::update_round_projects(round_origin(), 0, bounded_vec![], bounded_vec![0, 1]),
+            Error::<Test>::LengthMustExceedZero
+        );
+    });
+}
+
+#[test]
+fn update_round_projects_before_the_round_starts() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        for _ in 0..3 {
+            create_project(alice);
+        }
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::update_round_projects(
+            round_origin(),
+            0,
+            bounded_vec![2],
+            bounded_vec![0]
+        ));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::RoundProjectsUpdated(0, vec![1, 2]))
+        );
+        assert_eq!(Proposals::project_rounds(2, RoundType::ContributionRound), Some(0));
+
+        run_to_block(3);
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 2, 1_000u64));
+        assert_noop!(
+            Proposals::contribute(Origin::signed(bob), 0, 1_000u64),
+            Error::<Test>::RoundNotProcessing
+        );
+    });
+}
+
+#[test]
+fn round_projects_cannot_be_updated_once_the_round_started() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        for _ in 0..3 {
+            create_project(alice);
+        }
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        run_to_block(3);
+        assert_noop!(
+            Proposals::update_round_projects(round_origin(), 0, bounded_vec![2], bounded_vec![]),
+            Error::<Test>::RoundStarted
+        );
+    });
+}
+
+#[test]
+fn projects_removed_from_a_round_can_be_updated_again() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::update_round_projects(
+            round_origin(),
+            0,
+            bounded_vec![],
+            bounded_vec![0]
+        ));
+        assert!(!Proposals::projects(0).unwrap().approved_for_funding);
+        assert!(Proposals::projects(1).unwrap().approved_for_funding);
+        assert_eq!(Proposals::project_rounds(0, RoundType::ContributionRound), None);
+
+        let update = |project_key: ProjectKey| {
+            Proposals::update_project(
+                Origin::signed(alice),
+                project_key,
+                b"Farmer's Project Sudan 2".to_vec().try_into().expect("input should be of decent length"),
+                b"Imbue Logo".to_vec().try_into().expect("input should be of decent length"),
+                b"Fixed description".to_vec().try_into().expect("input should be of decent length"),
+                b"https://farmers.network".to_vec().try_into().expect("input should be of decent length"),
+                bounded_vec![ProposedMilestone { name: bounded_vec![], percentage_to_unlock: 100, deadline: None }],
+                2_000_000u64,
+            )
+        };
+        assert_ok!(update(0));
+        assert_noop!(update(1), Error::<Test>::ProjectApprovedForFunding);
+    });
+}
+
+#[test]
+fn projects_removed_from_a_round_stay_approved_while_scheduled_in_another_round() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 20,
+            System::block_number() + 30,
+            bounded_vec![0],
+            RoundType::VotingRound
+        ));
+        assert_ok!(Proposals::update_round_projects(
+            round_origin(),
+            0,
+            bounded_vec![],
+            bounded_vec![0]
+        ));
+        assert!(Proposals::projects(0).unwrap().approved_for_funding);
+    });
+}
+
+#[test]
+fn project_creation_reserves_a_storage_deposit() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        ProjectDepositPerItem::set(100);
+        ProjectDepositPerByte::set(1);
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        let project_deposit = Proposals::project_deposits(0);
+        assert!(project_deposit > 100);
+        assert_eq!(Balances::reserved_balance(&alice), project_deposit);
+
+        ProjectDepositPerItem::set(0);
+        ProjectDepositPerByte::set(0);
+    });
+}
+
+#[test]
+fn whitelist_deposits_are_released_with_the_whitelist() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        ProjectDepositPerItem::set(100);
+        ProjectDepositPerByte::set(1);
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        let project_deposit = Proposals::project_deposits(0);
+        assert_ok!(Proposals::add_project_whitelist(
+            Origin::signed(alice),
+            0,
+            bounded_vec![Whitelist { who: bob, max_cap: 1_000u64 }]
+        ));
+        let whitelist_deposit = Proposals::whitelist_deposits(0);
+        assert!(whitelist_deposit > 100);
+        assert_eq!(Balances::reserved_balance(&alice), project_deposit + whitelist_deposit);
+
+        assert_ok!(Proposals::remove_project_whitelist(Origin::signed(alice), 0));
+        assert_eq!(Balances::reserved_balance(&alice), project_deposit);
+
+        ProjectDepositPerItem::set(0);
+        ProjectDepositPerByte::set(0);
+    });
+}
+
+#[test]
+fn rejected_projects_slash_their_deposit_to_the_treasury() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        ProjectDepositPerItem::set(100);
+        ProjectDepositPerByte::set(1);
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        let project_deposit = Proposals::project_deposits(0);
+        assert_ok!(Proposals::reject_project(admin_origin(), 0));
+        assert_eq!(Balances::reserved_balance(&alice), 0);
+        assert_eq!(Balances::free_balance(&TreasuryAccount::get()), project_deposit);
+        assert!(Proposals::projects(0).unwrap().cancelled);
+
+        ProjectDepositPerItem::set(0);
+        ProjectDepositPerByte::set(0);
+    });
+}
+
+#[test]
+fn rejected_projects_cannot_be_rejected_again() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        ProjectDepositPerItem::set(100);
+        ProjectDepositPerByte::set(1);
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::reject_project(admin_origin(), 0));
+        assert_noop!(
+            Proposals::reject_project(admin_origin(), 0),
+            Error::<Test>::ProjectCancelled
+        );
+
+        ProjectDepositPerItem::set(0);
+        ProjectDepositPerByte::set(0);
+    });
+}
+
+#[test]
+fn projects_with_contributions_cannot_be_rejected() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        ProjectDepositPerItem::set(100);
+        ProjectDepositPerByte::set(1);
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000u64));
+        assert_noop!(
+            Proposals::reject_project(admin_origin(), 0),
+            Error::<Test>::ProjectHasContributions
+        );
+
+        ProjectDepositPerItem::set(0);
+        ProjectDepositPerByte::set(0);
+    });
+}
+
+#[test]
+fn refunded_projects_release_their_deposit() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        ProjectDepositPerItem::set(100);
+        ProjectDepositPerByte::set(1);
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000u64));
+        assert_ok!(Proposals::refund(refund_origin(), 0));
+        assert_eq!(Balances::reserved_balance(&alice), 0);
+        assert_eq!(Proposals::project_deposits(0), 0);
+
+        ProjectDepositPerItem::set(0);
+        ProjectDepositPerByte::set(0);
+    });
+}
+
+#[test]
+fn only_the_initiator_can_update_a_project() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_noop!(
+            Proposals::update_project(
+                Origin::signed(bob),
+                0,
+                b"Farmer's Project Sudan 2".to_vec().try_into().expect("input should be of decent length"),
+                b"Imbue Logo".to_vec().try_into().expect("input should be of decent length"),
+                b"Fixed description".to_vec().try_into().expect("input should be of decent length"),
+                b"https://farmers.network".to_vec().try_into().expect("input should be of decent length"),
+                two_milestones(),
+                2_000_000u64,
+            ),
+            Error::<Test>::UserIsNotInitator
+        );
+    });
+}
+
+#[test]
+fn project_updates_must_unlock_all_funds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_noop!(
+            Proposals::update_project(
+                Origin::signed(alice),
+                0,
+                b"Farmer's Project Sudan 2".to_vec().try_into().expect("input should be of decent length"),
+                b"Imbue Logo".to_vec().try_into().expect("input should be of decent length"),
+                b"Fixed description".to_vec().try_into().expect("input should be of decent length"),
+                b"https://farmers.network".to_vec().try_into().expect("input should be of decent length"),
+                bounded_vec![ProposedMilestone { name: bounded_vec![], percentage_to_unlock: 99, deadline: None }],
+                2_000_000u64,
+            ),
+            Error::<Test>::MilestonesTotalPercentageMustEqual100
+        );
+    });
+}
+
+#[test]
+fn initiator_can_update_project_until_it_is_scheduled() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_ok!(Proposals::update_project(
+            Origin::signed(alice),
+            0,
+            b"Farmer's Project Sudan 2".to_vec().try_into().expect("input should be of decent length"),
+            b"Imbue Logo".to_vec().try_into().expect("input should be of decent length"),
+            b"Fixed description".to_vec().try_into().expect("input should be of decent length"),
+            b"https://farmers.network".to_vec().try_into().expect("input should be of decent length"),
+            two_milestones(),
+            2_000_000u64,
+        ));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ProjectUpdated(alice, 0, 2_000_000u64))
+        );
+        let project = Proposals::projects(0).unwrap();
+        assert_eq!(project.name, b"Farmer's Project Sudan 2".to_vec());
+        assert_eq!(project.required_funds, 2_000_000u64);
+        assert_eq!(project.milestones.len(), 2);
+        assert_eq!(project.milestones[1].milestone_key, 1);
+    });
+}
+
+#[test]
+fn scheduled_projects_cannot_be_updated() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_noop!(
+            Proposals::update_project(
+                Origin::signed(alice),
+                0,
+                b"Farmer's Project Sudan 2".to_vec().try_into().expect("input should be of decent length"),
+                b"Imbue Logo".to_vec().try_into().expect("input should be of decent length"),
+                b"Fixed description".to_vec().try_into().expect("input should be of decent length"),
+                b"https://farmers.network".to_vec().try_into().expect("input should be of decent length"),
+                two_milestones(),
+                2_000_000u64,
+            ),
+            Error::<Test>::ProjectApprovedForFunding
+        );
+    });
+}
+
+#[test]
+fn ownership_transfers_can_only_be_proposed_by_the_initiator() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_noop!(
+            Proposals::propose_ownership_transfer(Origin::signed(bob), 0, bob),
+            Error::<Test>::UserIsNotInitator
+        );
+    });
+}
+
+#[test]
+fn ownership_transfers_can_only_be_accepted_when_proposed() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_noop!(
+            Proposals::accept_ownership_transfer(Origin::signed(charlie), 0),
+            Error::<Test>::NoPendingOwnershipTransfer
+        );
+    });
+}
+
+#[test]
+fn ownership_transfers_can_only_be_accepted_by_the_proposed_account() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_ok!(Proposals::propose_ownership_transfer(Origin::signed(alice), 0, charlie));
+        assert_noop!(
+            Proposals::accept_ownership_transfer(Origin::signed(bob), 0),
+            Error::<Test>::NoPendingOwnershipTransfer
+        );
+    });
+}
+
+#[test]
+fn accepted_ownership_transfers_change_the_initiator() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_ok!(Proposals::propose_ownership_transfer(Origin::signed(alice), 0, charlie));
+        assert_ok!(Proposals::accept_ownership_transfer(Origin::signed(charlie), 0));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::OwnershipTransferred(0, alice, charlie))
+        );
+        assert_eq!(Proposals::projects(0).unwrap().initiator, charlie);
+        assert!(Proposals::pending_ownership_transfers(0).is_none());
+        assert_noop!(
+            Proposals::set_beneficiary(Origin::signed(alice), 0, Some(alice)),
+            Error::<Test>::UserIsNotInitator
+        );
+    });
+}
+
+#[test]
+fn accepted_ownership_transfers_remove_the_team_and_beneficiary() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_ok!(Proposals::set_team_member(
+            Origin::signed(alice),
+            0,
+            bob,
+            bounded_vec![ProjectRole::SubmitMilestones, ProjectRole::Withdraw]
+        ));
+        assert_ok!(Proposals::set_beneficiary(Origin::signed(alice), 0, Some(dave)));
+
+        assert_ok!(Proposals::propose_ownership_transfer(Origin::signed(alice), 0, charlie));
+        assert_ok!(Proposals::accept_ownership_transfer(Origin::signed(charlie), 0));
+        assert!(Proposals::project_team(0, bob).is_none());
+        assert_eq!(Proposals::project_team_size(0), 0);
+        assert!(Proposals::project_beneficiaries(0).is_none());
+    });
+}
+
+#[test]
+fn project_teams_are_bounded() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        for member in [bob, charlie] {
+            assert_ok!(Proposals::set_team_member(
+                Origin::signed(alice),
+                0,
+                member,
+                bounded_vec![ProjectRole::SubmitMilestones]
+            ));
+        }
+        assert_noop!(
+            Proposals::set_team_member(Origin::signed(alice), 0, dave, bounded_vec![ProjectRole::Withdraw]),
+            Error::<Test>::TooManyTeamMembers
+        );
+
+        // Existing members can still be changed, and removed to make room
+        assert_ok!(Proposals::set_team_member(Origin::signed(alice), 0, bob, bounded_vec![ProjectRole::Withdraw]));
+        assert_ok!(Proposals::set_team_member(Origin::signed(alice), 0, bob, bounded_vec![]));
+        assert_ok!(Proposals::set_team_member(Origin::signed(alice), 0, dave, bounded_vec![ProjectRole::Withdraw]));
+        assert_eq!(Proposals::project_team_size(0), 2);
+    });
+}
+
+#[test]
+fn team_members_act_for_the_initiator_and_withdrawals_go_to_the_beneficiary() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let additional_amount = 10_000_000u64;
+    let contribution_amount = 1_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project(charlie);
+
+        // Alice is on the team to submit milestones, withdrawals go to dave
+        assert_ok!(Proposals::set_team_member(
+            Origin::signed(charlie),
+            0,
+            alice,
+            bounded_vec![ProjectRole::SubmitMilestones, ProjectRole::Withdraw]
+        ));
+        assert_ok!(Proposals::set_beneficiary(Origin::signed(charlie), 0, Some(dave)));
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, contribution_amount));
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+
+        assert_noop!(
+            Proposals::submit_milestone(Origin::signed(bob), 0, 0, milestone_evidence()),
+            Error::<Test>::UserIsNotInitator
+        );
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        run_to_block(4);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, true));
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(charlie), 0, 0));
+
+        assert_ok!(Proposals::withdraw(Origin::signed(alice), 0));
+        assert_eq!(Balances::free_balance(&dave), contribution_amount);
+
+        // Removing every role removes the member
+        assert_ok!(Proposals::set_team_member(Origin::signed(charlie), 0, alice, bounded_vec![]));
+        assert!(Proposals::project_team(0, alice).is_none());
+    });
+}
+
+#[test]
+fn only_contributors_can_raise_a_vote_of_no_confidence() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_noop!(
+            Proposals::raise_vote_of_no_confidence(Origin::signed(alice), 0),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+    });
+}
+
+#[test]
+fn no_confidence_votes_require_a_raised_round() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(Origin::signed(charlie), 0, true),
+            Error::<Test>::NoActiveNoConfidenceRound
+        );
+    });
+}
+
+#[test]
+fn only_contributors_can_vote_on_no_confidence_rounds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_ok!(Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0));
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(Origin::signed(alice), 0, true),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+    });
+}
+
+#[test]
+fn no_confidence_votes_can_only_be_cast_once() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_ok!(Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0));
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(Origin::signed(bob), 0, true),
+            Error::<Test>::VoteAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn no_confidence_below_the_threshold_does_not_refund() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        // 60% of the contributions are not enough
+        assert_ok!(Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0));
+        assert_eq!(Proposals::no_confidence_votes(0).unwrap().yay, 600_000u64);
+        assert!(!Proposals::projects(0).unwrap().cancelled);
+    });
+}
+
+#[test]
+fn no_confidence_rounds_expire_once_the_voting_period_ends() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_ok!(Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0));
+        let end = System::block_number() + NoConfidenceVotingPeriod::get();
+        assert_eq!(Proposals::no_confidence_round_ends(0), Some(end));
+
+        run_to_block(end + 1);
+        assert_eq!(Proposals::no_confidence_votes(0), None);
+        assert_eq!(Proposals::no_confidence_round_ends(0), None);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::NoConfidenceRoundExpired(0))
+        );
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(Origin::signed(charlie), 0, true),
+            Error::<Test>::NoActiveNoConfidenceRound
+        );
+
+        // A new vote can be raised, earlier votes do not count in it
+        assert_ok!(Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0));
+        assert_eq!(Proposals::no_confidence_votes(0).unwrap().yay, 600_000u64);
+        assert!(!Proposals::projects(0).unwrap().cancelled);
+    });
+}
+
+#[test]
+fn only_funded_projects_can_lose_confidence() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 500_000u64));
+        assert_noop!(
+            Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0),
+            Error::<Test>::ProjectNotFunded
+        );
+    });
+}
+
+#[test]
+fn vote_of_no_confidence_refunds_locked_milestones() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_ok!(Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0));
+        assert_ok!(Proposals::vote_on_no_confidence_round(Origin::signed(charlie), 0, true));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::NoConfidenceRoundFinalised(0))
+        );
+        assert!(Proposals::projects(0).unwrap().cancelled);
+        // Only the locked milestone is refunded
+        assert_eq!(Balances::free_balance(&bob), additional_amount - 300_000u64);
+        assert_eq!(Balances::free_balance(&charlie), additional_amount - 200_000u64);
+    });
+}
+
+#[test]
+fn no_confidence_votes_are_rejected_once_the_project_is_refunded() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_ok!(Proposals::raise_vote_of_no_confidence(Origin::signed(bob), 0));
+        assert_ok!(Proposals::refund(refund_origin(), 0));
+        assert_noop!(
+            Proposals::vote_on_no_confidence_round(Origin::signed(charlie), 0, true),
+            Error::<Test>::ProjectCancelled
+        );
+    });
+}
+
+#[test]
+fn refunded_projects_cannot_be_refunded_again() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_ok!(Proposals::refund(refund_origin(), 0));
+        assert_noop!(
+            Proposals::refund(refund_origin(), 0),
+            Error::<Test>::ProjectCancelled
+        );
+    });
+}
+
+#[test]
+fn schedule_round_requires_the_round_origin() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        let schedule_round = |origin: Origin| {
+            Proposals::schedule_round(
+                origin,
+                System::block_number() + 1,
+                System::block_number() + 10,
+                bounded_vec![0],
+                RoundType::ContributionRound,
+            )
+        };
+        for origin in [Origin::signed(alice), Origin::none(), Origin::root(), admin_origin(), refund_origin()] {
+            assert_noop!(schedule_round(origin), DispatchError::BadOrigin);
+        }
+        assert_ok!(schedule_round(round_origin()));
+        assert_eq!(Proposals::project_rounds(0, RoundType::ContributionRound), Some(0));
+    });
+}
+
+#[test]
+fn approve_requires_the_admin_origin() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+        run_to_block(3);
+
+        for origin in [Origin::signed(alice), Origin::none(), Origin::root(), round_origin(), refund_origin()] {
+            assert_noop!(Proposals::approve(origin, 0, None), DispatchError::BadOrigin);
+        }
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert!(Proposals::projects(0).unwrap().funding_threshold_met);
+    });
+}
+
+#[test]
+fn refund_requires_the_refund_origin() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[50, 50]), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        for origin in [Origin::signed(bob), Origin::none(), Origin::root(), admin_origin(), round_origin()] {
+            assert_noop!(Proposals::refund(origin, 0), DispatchError::BadOrigin);
+        }
+        assert_ok!(Proposals::refund(refund_origin(), 0));
+        assert!(Proposals::projects(0).unwrap().cancelled);
+    });
+}
+
+#[test]
+fn round_extrinsics_require_the_round_origin() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() + 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+
+        for origin in [Origin::signed(alice), Origin::root(), admin_origin(), refund_origin()] {
+            assert_noop!(
+                Proposals::update_round_projects(origin.clone(), 0, bounded_vec![1], bounded_vec![]),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(Proposals::cancel_round(origin, 0), DispatchError::BadOrigin);
+        }
+        assert_ok!(Proposals::update_round_projects(round_origin(), 0, bounded_vec![1], bounded_vec![]));
+        assert_ok!(Proposals::cancel_round(round_origin(), 0));
+    });
+}
+
+#[test]
+fn admin_extrinsics_require_the_admin_origin() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+
+        for origin in [Origin::signed(alice), Origin::root(), round_origin(), refund_origin()] {
+            assert_noop!(
+                Proposals::set_max_proposal_count_per_round(origin.clone(), 2),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(Proposals::set_milestone_voting_window(origin.clone(), 10), DispatchError::BadOrigin);
+            assert_noop!(
+                Proposals::set_milestone_voting_quorum(origin.clone(), Perbill::from_percent(10)),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Proposals::set_funding_soft_cap(origin.clone(), Perbill::from_percent(10)),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Proposals::set_milestone_approval_threshold(origin.clone(), Perbill::from_percent(10)),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(Proposals::set_withdrawal_expiration(origin.clone(), 10), DispatchError::BadOrigin);
+            assert_noop!(Proposals::set_is_identity_required(origin.clone(), true), DispatchError::BadOrigin);
+            assert_noop!(
+                Proposals::fund_matching_pool_from_treasury(origin.clone(), 0, CurrencyId::Native, 1_000u64),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(Proposals::reject_project(origin, 0), DispatchError::BadOrigin);
+        }
+        assert_ok!(Proposals::set_max_proposal_count_per_round(admin_origin(), 2));
+        assert_eq!(Proposals::max_proposal_count_per_round(), 2);
+    });
+}
+
+#[test]
+fn milestone_deadlines_must_be_in_the_future() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        assert_noop!(
+            Proposals::create_project(
+                Origin::signed(alice),
+                b"Imbue's Awesome Initiative".to_vec().try_into().expect("input should be of decent length"),
+                b"Imbue Logo".to_vec().try_into().expect("input should be of decent length"),
+                b"This project is aimed at promoting Decentralised Data and Transparent Crowdfunding."
+                    .to_vec()
+                    .try_into()
+                    .expect("input should be of decent length"),
+                b"https://imbue.network".to_vec().try_into().expect("input should be of decent length"),
+                milestones_due_at(1).try_into().expect("proposed milestones are too long"),
+                1000000u64,
+                CurrencyId::Native
+            ),
+            Error::<Test>::MilestoneDeadlineInPast
+        );
+    });
+}
+
+#[test]
+fn milestone_deadlines_are_stored_when_the_project_is_created() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project_multiple_milestones(alice, milestones_due_at(10));
+        assert_eq!(Proposals::milestone_deadlines((0, 0)), Some(10));
+        assert_eq!(Proposals::milestone_deadlines((0, 1)), None);
+    });
+}
+
+#[test]
+fn missed_milestone_deadlines_open_an_overdue_vote() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones_due_at(10), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(11);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::MilestoneOverdue(0, 0, 10))
+        );
+        assert!(Proposals::overdue_milestone_votes((0, 0)).is_some());
+    });
+}
+
+#[test]
+fn only_overdue_milestones_can_be_voted_on() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones_due_at(10), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(11);
+        assert_noop!(
+            Proposals::vote_on_overdue_milestone(Origin::signed(bob), 0, 1, false),
+            Error::<Test>::MilestoneNotOverdue
+        );
+    });
+}
+
+#[test]
+fn only_contributors_can_vote_on_overdue_milestones() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones_due_at(10), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(11);
+        assert_noop!(
+            Proposals::vote_on_overdue_milestone(Origin::signed(alice), 0, 0, false),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+    });
+}
+
+#[test]
+fn overdue_milestone_votes_can_only_be_cast_once() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones_due_at(10), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(11);
+        assert_ok!(Proposals::vote_on_overdue_milestone(Origin::signed(charlie), 0, 0, false));
+        assert_noop!(
+            Proposals::vote_on_overdue_milestone(Origin::signed(charlie), 0, 0, true),
+            Error::<Test>::VoteAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn a_majority_against_a_refund_extends_the_deadline() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones_due_at(10), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(11);
+        assert_ok!(Proposals::vote_on_overdue_milestone(Origin::signed(charlie), 0, 0, false));
+        assert_ok!(Proposals::vote_on_overdue_milestone(Origin::signed(bob), 0, 0, false));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::MilestoneDeadlineExtended(0, 0, 111))
+        );
+        assert_eq!(Proposals::overdue_milestone_votes((0, 0)), None);
+    });
+}
+
+#[test]
+fn a_majority_for_a_refund_cancels_the_project() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones_due_at(10), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(11);
+        let additional_amount = 10_000_000u64;
+        assert_ok!(Proposals::vote_on_overdue_milestone(Origin::signed(bob), 0, 0, true));
+        assert!(Proposals::projects(0).unwrap().cancelled);
+        assert_eq!(Balances::free_balance(&bob), additional_amount);
+        assert_eq!(Balances::free_balance(&charlie), additional_amount);
+    });
+}
+
+#[test]
+fn missing_an_extended_deadline_opens_a_new_vote() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones_due_at(10), || {}, 1, &[(bob, 600_000u64), (charlie, 400_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        run_to_block(11);
+        assert_ok!(Proposals::vote_on_overdue_milestone(Origin::signed(charlie), 0, 0, false));
+        assert_ok!(Proposals::vote_on_overdue_milestone(Origin::signed(bob), 0, 0, false));
+
+        run_to_block(112);
+        assert!(Proposals::overdue_milestone_votes((0, 0)).is_some());
+        assert_ok!(Proposals::vote_on_overdue_milestone(Origin::signed(bob), 0, 0, true));
+        assert!(Proposals::projects(0).unwrap().cancelled);
+    });
+}
+
+#[test]
+fn milestone_submissions_keep_their_evidence() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+    let updated_evidence: BoundedEvidenceField = b"ipfs://QmUpdatedMilestoneEvidence"
+        .to_vec()
+        .try_into()
+        .expect("input should be of decent length");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+
+        assert_noop!(
+            Proposals::submit_milestone(Origin::signed(alice), 0, 0, bounded_vec![]),
+            Error::<Test>::EvidenceIsMandatory
+        );
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| record.event
+            == mock::Event::from(proposals::Event::MilestoneSubmitted(
+                alice,
+                0,
+                0,
+                milestone_evidence().to_vec()
+            ))));
+
+        // Resubmitting a rejected milestone keeps the earlier evidence
+        run_to_block(4);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+        run_to_block(14);
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, updated_evidence.clone()));
+        assert_eq!(
+            Proposals::milestone_evidence((0, 0)),
+            vec![milestone_evidence().to_vec(), updated_evidence.to_vec()]
+        );
+    });
+}
+
+#[test]
+fn only_existing_milestones_can_be_submitted() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert_noop!(
+            Proposals::submit_milestone(Origin::signed(alice), 0, 1, milestone_evidence()),
+            Error::<Test>::MilestoneDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn milestones_cannot_be_resubmitted_while_voting_is_open() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        submit_first_milestone(alice);
+        assert_noop!(
+            Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()),
+            Error::<Test>::MilestoneVotingInProgress
+        );
+    });
+}
+
+#[test]
+fn milestone_voting_cannot_be_finalised_twice() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        reject_first_milestone(alice, bob);
+        assert_noop!(
+            Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0),
+            Error::<Test>::MilestoneVotingNotActive
+        );
+    });
+}
+
+#[test]
+fn rejected_milestones_cannot_be_resubmitted_before_the_cooldown() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        reject_first_milestone(alice, bob);
+        run_to_block(13);
+        assert_noop!(
+            Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()),
+            Error::<Test>::MilestoneResubmissionTooEarly
+        );
+    });
+}
+
+#[test]
+fn rejected_milestones_can_be_resubmitted_after_the_cooldown() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        reject_first_milestone(alice, bob);
+
+        // The resubmission starts a fresh vote in a new round
+        run_to_block(14);
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().nay, 0);
+        run_to_block(15);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+
+        let submissions = Proposals::milestone_submissions((0, 0));
+        assert_eq!(submissions.len(), 2);
+        assert_eq!(submissions[0].round_key, 1);
+        assert_eq!(submissions[0].status, MilestoneSubmissionStatus::Rejected(4));
+        assert_eq!(submissions[1].round_key, 2);
+        assert_eq!(submissions[1].status, MilestoneSubmissionStatus::Rejected(15));
+        assert_eq!(submissions[1].votes.nay, 1_000_000u64);
+    });
+}
+
+#[test]
+fn milestones_cannot_be_resubmitted_more_than_the_maximum() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        reject_first_milestone(alice, bob);
+        run_to_block(14);
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        run_to_block(15);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+
+        // Only a single resubmission is allowed in the mock
+        run_to_block(25);
+        assert_noop!(
+            Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()),
+            Error::<Test>::MaxMilestoneResubmissionsReached
+        );
+    });
+}
+
+#[test]
+fn matching_pools_keep_the_currency_they_were_funded_in() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        let _ = Currencies::deposit(CurrencyId::Native, &dave, 10_000_000u64);
+        let _ = Currencies::deposit(CurrencyId::KSM, &dave, 10_000_000u64);
+        fund_project(alice, milestones(&[100]), || {}, 4, &[]);
+        assert_ok!(Proposals::fund_matching_pool(Origin::signed(dave), 0, CurrencyId::Native, 60_000u64));
+        assert_noop!(
+            Proposals::fund_matching_pool(Origin::signed(dave), 0, CurrencyId::KSM, 60_000u64),
+            Error::<Test>::MatchingPoolCurrencyMismatch
+        );
+    });
+}
+
+#[test]
+fn matching_pools_are_funded_from_the_treasury_by_the_admin_origin() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let treasury = TreasuryAccount::get();
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        let _ = Currencies::deposit(CurrencyId::Native, &treasury, additional_amount);
+        fund_project(alice, milestones(&[100]), || {}, 4, &[]);
+        assert_noop!(
+            Proposals::fund_matching_pool_from_treasury(Origin::signed(dave), 0, CurrencyId::Native, 40_000u64),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Proposals::fund_matching_pool_from_treasury(admin_origin(), 0, CurrencyId::Native, 40_000u64));
+        assert_eq!(Balances::free_balance(&treasury), additional_amount - 40_000u64);
+        assert_eq!(Proposals::matching_pools(0).unwrap().total, 40_000u64);
+    });
+}
+
+#[test]
+fn matching_pool_is_distributed_quadratically_when_the_round_ends() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        for account in [&charlie, &dave, &eve] {
+            let _ = Currencies::deposit(CurrencyId::Native, account, additional_amount);
+        }
+        create_project(alice);
+        create_project(alice);
+        // Both projects are funded once they raised 10k
+        assert_ok!(Proposals::set_funding_soft_cap(admin_origin(), Perbill::from_percent(1)));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+
+        // Two contributors of 10k match as much as a single contributor of 40k
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 10_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(charlie), 0, 10_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(eve), 1, 40_000u64));
+
+        assert_ok!(Proposals::fund_matching_pool(Origin::signed(dave), 0, CurrencyId::Native, 100_000u64));
+
+        run_to_block(5);
+        assert_eq!(Proposals::matching_pools(0), None);
+        assert_eq!(Proposals::project_matched_funds(0), 50_000u64);
+        assert_eq!(Proposals::project_matched_funds(1), 50_000u64);
+        assert_eq!(Balances::free_balance(&Proposals::project_account_id(0)), 70_000u64);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::MatchingFundsDistributed(0, 1, 50_000u64))
+        );
+
+        // The matched funds are released with the milestones
+        run_to_block(6);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_ok!(Proposals::withdraw(Origin::signed(alice), 0));
+        assert_eq!(Balances::free_balance(&alice), additional_amount + 70_000u64);
+    });
+}
+
+#[test]
+fn failed_matching_pool_distributions_are_retried() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        let _ = Currencies::deposit(CurrencyId::Native, &dave, additional_amount);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+        assert_ok!(Proposals::fund_matching_pool(Origin::signed(dave), 0, CurrencyId::Native, 60_000u64));
+
+        // The pool account can no longer pay out
+        let pool_account = Proposals::matching_pool_account_id(0);
+        assert_ok!(Currencies::withdraw(CurrencyId::Native, &pool_account, 60_000u64));
+
+        run_to_block(5);
+        assert!(matches!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::Proposals(proposals::Event::MatchingPoolDistributionFailed(0, _))
+        ));
+        assert!(Proposals::matching_pools(0).is_some());
+        assert_eq!(Proposals::matching_pool_sponsors(0, &dave), 60_000u64);
+        assert_eq!(Proposals::matching_pools_ending_at(6), vec![0]);
+        assert_eq!(Proposals::project_matched_funds(0), 0u64);
+
+        let _ = Currencies::deposit(CurrencyId::Native, &pool_account, 60_000u64);
+        run_to_block(6);
+        assert_eq!(Proposals::matching_pools(0), None);
+        assert_eq!(Proposals::project_matched_funds(0), 60_000u64);
+    });
+}
+
+#[test]
+fn matching_pools_only_match_funded_projects() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        for account in [&dave, &eve] {
+            let _ = Currencies::deposit(CurrencyId::Native, account, additional_amount);
+        }
+        create_project(alice);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(eve), 1, 40_000u64));
+        assert_ok!(Proposals::fund_matching_pool(Origin::signed(dave), 0, CurrencyId::Native, 60_000u64));
+
+        // Project 1 is short of its required funds and gets nothing
+        run_to_block(5);
+        assert_eq!(Proposals::project_matched_funds(0), 60_000u64);
+        assert_eq!(Proposals::project_matched_funds(1), 0u64);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::MatchingFundsDistributed(0, 0, 60_000u64))
+        );
+    });
+}
+
+#[test]
+fn matching_pools_only_count_the_contributions_of_their_round() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        for account in [&charlie, &dave, &eve] {
+            let _ = Currencies::deposit(CurrencyId::Native, account, additional_amount);
+        }
+        create_project(alice);
+        create_project(alice);
+        assert_ok!(Proposals::set_funding_soft_cap(admin_origin(), Perbill::from_percent(1)));
+
+        // Project 0 was not funded in an earlier round
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 5_000u64));
+        run_to_block(6);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(charlie), 0, 10_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(eve), 1, 10_000u64));
+        assert_ok!(Proposals::fund_matching_pool(Origin::signed(dave), 1, CurrencyId::Native, 60_000u64));
+
+        run_to_block(10);
+        assert_eq!(Proposals::project_matched_funds(0), 30_000u64);
+        assert_eq!(Proposals::project_matched_funds(1), 30_000u64);
+    });
+}
+
+#[test]
+fn contributions_cannot_be_withdrawn_beyond_what_was_contributed() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::add_project_whitelist(
+                    Origin::signed(alice),
+                    0,
+                    bounded_vec![Whitelist { who: bob, max_cap: 40_000u64 }]
+                ));
+            },
+            4,
+            &[(bob, 30_000u64)],
+        );
+        assert_noop!(
+            Proposals::withdraw_contribution(Origin::signed(bob), 0, 30_001u64),
+            Error::<Test>::WithdrawalExceedsContribution
+        );
+    });
+}
+
+#[test]
+fn only_contributors_can_withdraw_contributions() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::add_project_whitelist(
+                    Origin::signed(alice),
+                    0,
+                    bounded_vec![Whitelist { who: bob, max_cap: 40_000u64 }]
+                ));
+            },
+            4,
+            &[(bob, 30_000u64)],
+        );
+        assert_noop!(
+            Proposals::withdraw_contribution(Origin::signed(alice), 0, 1u64),
+            Error::<Test>::KeyNotFound
+        );
+    });
+}
+
+#[test]
+fn contributions_can_be_withdrawn_while_the_round_is_open() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::add_project_whitelist(
+                    Origin::signed(alice),
+                    0,
+                    bounded_vec![Whitelist { who: bob, max_cap: 40_000u64 }]
+                ));
+            },
+            4,
+            &[(bob, 30_000u64)],
+        );
+        assert_ok!(Proposals::withdraw_contribution(Origin::signed(bob), 0, 10_000u64));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ContributionWithdrawn(
+                bob,
+                0,
+                10_000u64,
+                CurrencyId::Native,
+                System::block_number()
+            ))
+        );
+        assert_eq!(Proposals::contributions(0, &bob), Some(20_000u64));
+        assert_eq!(Proposals::project_contributions_total(0), 20_000u64);
+        assert_eq!(Balances::free_balance(&bob), additional_amount - 20_000u64);
+    });
+}
+
+#[test]
+fn whitelist_caps_are_measured_against_the_remaining_contribution() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::add_project_whitelist(
+                    Origin::signed(alice),
+                    0,
+                    bounded_vec![Whitelist { who: bob, max_cap: 40_000u64 }]
+                ));
+            },
+            4,
+            &[(bob, 30_000u64)],
+        );
+        assert_noop!(
+            Proposals::contribute(Origin::signed(bob), 0, 15_000u64),
+            Error::<Test>::ContributionMustBeLowerThanMaxCap
+        );
+        assert_ok!(Proposals::withdraw_contribution(Origin::signed(bob), 0, 10_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 15_000u64));
+        assert_eq!(Proposals::contributions(0, &bob), Some(35_000u64));
+    });
+}
+
+#[test]
+fn withdrawing_the_whole_contribution_removes_it() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::add_project_whitelist(
+                    Origin::signed(alice),
+                    0,
+                    bounded_vec![Whitelist { who: bob, max_cap: 40_000u64 }]
+                ));
+            },
+            4,
+            &[(bob, 30_000u64)],
+        );
+        assert_ok!(Proposals::withdraw_contribution(Origin::signed(bob), 0, 30_000u64));
+        assert_eq!(Proposals::contributions(0, &bob), None);
+        assert_eq!(Proposals::project_contributions_total(0), 0u64);
+        assert_eq!(Proposals::round_contributions((0, 0), &bob), 0u64);
+        assert_eq!(Balances::free_balance(&bob), additional_amount);
+    });
+}
+
+#[test]
+fn contributions_cannot_be_withdrawn_once_the_round_ended() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::add_project_whitelist(
+                    Origin::signed(alice),
+                    0,
+                    bounded_vec![Whitelist { who: bob, max_cap: 40_000u64 }]
+                ));
+            },
+            4,
+            &[(bob, 30_000u64)],
+        );
+        run_to_block(5);
+        assert_noop!(
+            Proposals::withdraw_contribution(Origin::signed(bob), 0, 10_000u64),
+            Error::<Test>::RoundNotProcessing
+        );
+    });
+}
+
+#[test]
+fn only_the_initiator_can_reserve_contributions() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_noop!(
+            Proposals::set_reserve_contributions(Origin::signed(bob), 0, true),
+            Error::<Test>::UserIsNotInitator
+        );
+        assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ContributionModeUpdated(0, true))
+        );
+    });
+}
+
+#[test]
+fn contribution_mode_is_fixed_once_the_project_has_contributions() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+            },
+            10,
+            &[(bob, 600_000u64)],
+        );
+        assert_noop!(
+            Proposals::set_reserve_contributions(Origin::signed(alice), 0, false),
+            Error::<Test>::ProjectHasContributions
+        );
+    });
+}
+
+#[test]
+fn reserved_contributions_stay_with_the_contributor() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+            },
+            10,
+            &[(bob, 600_000u64)],
+        );
+        assert_eq!(
+            Balances::reserved_balance_named(&Proposals::contribution_reserve_id(0), &bob),
+            600_000u64
+        );
+        assert_eq!(Balances::free_balance(&Proposals::project_account_id(0)), 0u64);
+
+        assert_ok!(Proposals::withdraw_contribution(Origin::signed(bob), 0, 100_000u64));
+        assert_eq!(Balances::reserved_balance(&bob), 500_000u64);
+        assert_eq!(Balances::free_balance(&bob), additional_amount - 500_000u64);
+    });
+}
+
+#[test]
+fn reserved_contributions_move_to_the_project_once_funded() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert_eq!(Balances::reserved_balance(&bob), 0u64);
+        assert_eq!(Balances::free_balance(&bob), additional_amount - 1_000_000u64);
+        assert_eq!(Balances::free_balance(&Proposals::project_account_id(0)), 1_000_000u64);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ProjectApproved(1, 0))
+        );
+    });
+}
+
+#[test]
+fn moving_reserved_contributions_leaves_other_reserves_untouched() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Balances::reserve(&bob, 50_000u64));
+        // A slashed contribution is not made up for with the contributor's other reserves
+        let _ = Balances::slash_reserved_named(&Proposals::contribution_reserve_id(0), &bob, 100_000u64);
+
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert_eq!(Balances::reserved_balance(&bob), 50_000u64);
+        assert_eq!(Balances::free_balance(&Proposals::project_account_id(0)), 900_000u64);
+        assert_eq!(Proposals::contributions(0, &bob), Some(900_000u64));
+    });
+}
+
+#[test]
+fn anyone_can_release_reserved_contributions_of_unfunded_projects() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+            },
+            10,
+            &[(bob, 600_000u64)],
+        );
+        run_to_block(12);
+        assert_ok!(Proposals::release_reserved_contributions(Origin::signed(charlie), 0));
+        assert_eq!(Balances::reserved_balance(&bob), 0u64);
+        assert_eq!(Balances::free_balance(&bob), additional_amount);
+        assert_eq!(Proposals::contributions(0, &bob), None);
+        assert_eq!(Proposals::project_contributions_total(0), 0u64);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ReservedContributionsReleased(0, 600_000u64))
+        );
+    });
+}
+
+#[test]
+fn reserved_contributions_cannot_be_released_while_the_round_is_open() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+            },
+            10,
+            &[(bob, 600_000u64)],
+        );
+        assert_noop!(
+            Proposals::release_reserved_contributions(Origin::signed(bob), 0),
+            Error::<Test>::RoundNotEnded
+        );
+    });
+}
+
+#[test]
+fn reserved_contributions_of_fundable_projects_cannot_be_released() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+            },
+            10,
+            &[(bob, 600_000u64)],
+        );
+        ProjectFundingCaps::<Test>::insert(
+            0,
+            FundingCaps {
+                soft_cap: Some(Perbill::from_percent(50)),
+                hard_cap: None,
+                return_overfunding: false,
+            },
+        );
+        run_to_block(12);
+        assert_noop!(
+            Proposals::release_reserved_contributions(Origin::signed(bob), 0),
+            Error::<Test>::ProjectCanStillBeFunded
+        );
+    });
+}
+
+#[test]
+fn only_reserved_contributions_can_be_released() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 600_000u64));
+        run_to_block(12);
+        assert_noop!(
+            Proposals::release_reserved_contributions(Origin::signed(bob), 0),
+            Error::<Test>::ContributionsNotReserved
+        );
+    });
+}
+
+#[test]
+fn hard_caps_cannot_be_below_the_required_funds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        assert_noop!(
+            Proposals::set_funding_caps(
+                Origin::signed(alice),
+                0,
+                FundingCaps {
+                    soft_cap: None,
+                    hard_cap: Some(500_000u64),
+                    return_overfunding: true,
+                }
+            ),
+            Error::<Test>::HardCapBelowRequiredFunds
+        );
+        assert_noop!(
+            Proposals::set_funding_caps(
+                Origin::signed(bob),
+                0,
+                FundingCaps {
+                    soft_cap: None,
+                    hard_cap: Some(1_250_000u64),
+                    return_overfunding: true,
+                }
+            ),
+            Error::<Test>::UserIsNotInitator
+        );
+    });
+}
+
+#[test]
+fn contributions_cannot_exceed_the_hard_cap() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::set_funding_caps(
+            Origin::signed(alice),
+            0,
+            FundingCaps {
+                soft_cap: None,
+                hard_cap: Some(1_250_000u64),
+                return_overfunding: true,
+            }
+        ));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+
+        assert_noop!(
+            Proposals::contribute(Origin::signed(bob), 0, 1_250_001u64),
+            Error::<Test>::HardCapExceeded
+        );
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_250_000u64));
+        assert_noop!(
+            Proposals::contribute(Origin::signed(bob), 0, 1u64),
+            Error::<Test>::HardCapExceeded
+        );
+    });
+}
+
+#[test]
+fn overfunding_is_returned_to_the_contributors_on_approval() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        let _ = Currencies::deposit(CurrencyId::Native, &charlie, additional_amount);
+        create_project(alice);
+        assert_ok!(Proposals::set_funding_caps(
+            Origin::signed(alice),
+            0,
+            FundingCaps {
+                soft_cap: None,
+                hard_cap: Some(1_250_000u64),
+                return_overfunding: true,
+            }
+        ));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(charlie), 0, 250_000u64));
+
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert_eq!(Proposals::contributions(0, &bob), Some(800_000u64));
+        assert_eq!(Proposals::contributions(0, &charlie), Some(200_000u64));
+        assert_eq!(Proposals::project_contributions_total(0), 1_000_000u64);
+        assert_eq!(Balances::free_balance(&Proposals::project_account_id(0)), 1_000_000u64);
+        assert_eq!(Balances::free_balance(&charlie), additional_amount - 200_000u64);
+    });
+}
+
+#[test]
+fn projects_short_of_their_required_funds_wait_for_the_round_to_end() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::set_funding_caps(
+            Origin::signed(alice),
+            0,
+            FundingCaps {
+                soft_cap: Some(Perbill::from_percent(50)),
+                hard_cap: None,
+                return_overfunding: false,
+            }
+        ));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 600_000u64));
+        assert_noop!(
+            Proposals::approve(admin_origin(), 0, None),
+            Error::<Test>::RoundNotEnded
+        );
+    });
+}
+
+#[test]
+fn project_soft_caps_decide_once_the_round_ended() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::set_funding_caps(
+            Origin::signed(alice),
+            0,
+            FundingCaps {
+                soft_cap: Some(Perbill::from_percent(50)),
+                hard_cap: None,
+                return_overfunding: false,
+            }
+        ));
+        assert_ok!(Proposals::set_funding_soft_cap(admin_origin(), Perbill::from_percent(80)));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 600_000u64));
+
+        run_to_block(6);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert!(Proposals::projects(0).unwrap().funding_threshold_met);
+    });
+}
+
+#[test]
+fn projects_without_a_soft_cap_use_the_default_soft_cap() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_ok!(Proposals::set_funding_soft_cap(admin_origin(), Perbill::from_percent(80)));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 700_000u64));
+
+        run_to_block(6);
+        assert_noop!(
+            Proposals::approve(admin_origin(), 0, None),
+            Error::<Test>::FundingSoftCapNotMet
+        );
+    });
+}
+
+#[test]
+fn projects_without_any_soft_cap_must_raise_their_required_funds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, 10_000_000u64);
+        create_project(alice);
+        assert_eq!(Proposals::funding_soft_cap(), Perbill::zero());
+        assert_ok!(Proposals::set_reserve_contributions(Origin::signed(alice), 0, true));
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 4,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 999_999u64));
+
+        run_to_block(6);
+        assert_noop!(
+            Proposals::approve(admin_origin(), 0, None),
+            Error::<Test>::FundingSoftCapNotMet
+        );
+        assert_ok!(Proposals::release_reserved_contributions(Origin::signed(bob), 0));
+    });
+}
+
+#[test]
+fn vesting_periods_cannot_change_once_the_project_is_funded() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_vesting_period(Origin::signed(alice), 0, Some(100)));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_noop!(
+            Proposals::set_vesting_period(Origin::signed(alice), 0, None),
+            Error::<Test>::FundingThresholdMet
+        );
+    });
+}
+
+#[test]
+fn withdrawals_vest_over_the_project_vesting_period() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_vesting_period(Origin::signed(alice), 0, Some(100)));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+
+        // Nothing is paid out when withdrawing
+        let alice_balance = Balances::free_balance(&alice);
+        let start = System::block_number();
+        assert_ok!(Proposals::withdraw(Origin::signed(alice), 0));
+        assert_eq!(Balances::free_balance(&alice), alice_balance);
+        assert_eq!(
+            Proposals::vesting_schedules(0),
+            vec![VestingSchedule {
+                total: 1_000_000u64,
+                claimed: 0u64,
+                start,
+                period: 100,
+            }]
+        );
+        assert_eq!(Proposals::get_withdrawable_funds(0).unwrap(), 0u64);
+    });
+}
+
+#[test]
+fn vested_funds_are_claimed_as_they_vest() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_vesting_period(Origin::signed(alice), 0, Some(100)));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        let alice_balance = Balances::free_balance(&alice);
+        let start = System::block_number();
+        assert_ok!(Proposals::withdraw(Origin::signed(alice), 0));
+
+        run_to_block(start + 25);
+        assert_eq!(Proposals::get_claimable_funds(0), 250_000u64);
+        assert_ok!(Proposals::claim_vested_funds(Origin::signed(alice), 0));
+        assert_eq!(Balances::free_balance(&alice), alice_balance + 250_000u64);
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::VestedFundsClaimed(
+                alice,
+                0,
+                250_000u64,
+                CurrencyId::Native
+            ))
+        );
+    });
+}
+
+#[test]
+fn vested_funds_cannot_be_claimed_twice_in_a_block() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_vesting_period(Origin::signed(alice), 0, Some(100)));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        let start = System::block_number();
+        assert_ok!(Proposals::withdraw(Origin::signed(alice), 0));
+
+        run_to_block(start + 25);
+        assert_ok!(Proposals::claim_vested_funds(Origin::signed(alice), 0));
+        assert_noop!(
+            Proposals::claim_vested_funds(Origin::signed(alice), 0),
+            Error::<Test>::NoVestedFundsToClaim
+        );
+    });
+}
+
+#[test]
+fn only_the_initiator_can_claim_vested_funds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_vesting_period(Origin::signed(alice), 0, Some(100)));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        let start = System::block_number();
+        assert_ok!(Proposals::withdraw(Origin::signed(alice), 0));
+
+        run_to_block(start + 25);
+        assert_noop!(
+            Proposals::claim_vested_funds(Origin::signed(bob), 0),
+            Error::<Test>::InvalidAccount
+        );
+    });
+}
+
+#[test]
+fn fully_claimed_vesting_schedules_are_removed() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {
+                assert_ok!(Proposals::set_vesting_period(Origin::signed(alice), 0, Some(100)));
+            },
+            10,
+            &[(bob, 1_000_000u64)],
+        );
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        let alice_balance = Balances::free_balance(&alice);
+        let start = System::block_number();
+        assert_ok!(Proposals::withdraw(Origin::signed(alice), 0));
+
+        run_to_block(start + 25);
+        assert_ok!(Proposals::claim_vested_funds(Origin::signed(alice), 0));
+        run_to_block(start + 150);
+        assert_ok!(Proposals::claim_vested_funds(Origin::signed(alice), 0));
+        assert_eq!(Balances::free_balance(&alice), alice_balance + 1_000_000u64);
+        assert!(Proposals::vesting_schedules(0).is_empty());
+    });
+}
+
+#[test]
+fn votes_cannot_be_delegated_to_self() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_noop!(
+            Proposals::delegate_votes(Origin::signed(charlie), None, charlie),
+            Error::<Test>::CannotDelegateToSelf
+        );
+        assert_noop!(
+            Proposals::delegate_votes(Origin::signed(charlie), Some(0), charlie),
+            Error::<Test>::CannotDelegateToSelf
+        );
+    });
+}
+
+#[test]
+fn revoking_a_missing_vote_delegation_fails() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_noop!(
+            Proposals::revoke_vote_delegation(Origin::signed(charlie), None),
+            Error::<Test>::NoVoteDelegation
+        );
+        // A global delegation is not revoked by revoking the project delegation
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), None, eve));
+        assert_noop!(
+            Proposals::revoke_vote_delegation(Origin::signed(charlie), Some(0)),
+            Error::<Test>::NoVoteDelegation
+        );
+    });
+}
+
+#[test]
+fn project_vote_delegations_take_precedence() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::delegate_votes(Origin::signed(dave), None, bob));
+        assert_ok!(Proposals::delegate_votes(Origin::signed(dave), Some(0), eve));
+        assert_eq!(Proposals::vote_delegate(&dave, 0), Some(eve));
+
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, true));
+        // Bob only votes with his own contribution
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().yay, 400_000u64);
+        let round_key = Proposals::milestone_voting_rounds((0, 0)).unwrap();
+        assert!(Proposals::vote_cast_by((dave, 0, 0, round_key)).is_none());
+    });
+}
+
+#[test]
+fn delegates_vote_with_the_contributions_delegated_to_them() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), None, eve));
+        assert_ok!(Proposals::delegate_votes(Origin::signed(dave), Some(0), eve));
+
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(eve), 0, 0, true));
+        let round_key = Proposals::milestone_voting_rounds((0, 0)).unwrap();
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().yay, 600_000u64);
+        let mut delegators = Proposals::delegated_votes((eve, 0, 0, round_key));
+        delegators.sort();
+        let mut expected_delegators = vec![charlie, dave];
+        expected_delegators.sort();
+        assert_eq!(delegators, expected_delegators);
+        assert_eq!(
+            Proposals::get_user_milestone_votes(0, charlie),
+            vec![(0, round_key, true)]
+        );
+    });
+}
+
+#[test]
+fn delegators_cannot_vote_again_after_their_delegate_voted() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), None, eve));
+
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(eve), 0, 0, true));
+        assert_noop!(
+            Proposals::vote_on_milestone(Origin::signed(charlie), 0, 0, false),
+            Error::<Test>::VoteAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn revoking_a_vote_delegation_keeps_cast_votes() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::delegate_votes(Origin::signed(dave), None, bob));
+        assert_ok!(Proposals::delegate_votes(Origin::signed(dave), Some(0), eve));
+
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(eve), 0, 0, true));
+        assert_ok!(Proposals::revoke_vote_delegation(Origin::signed(dave), Some(0)));
+        assert!(Proposals::project_delegators(0, &eve).is_empty());
+        assert_eq!(Proposals::vote_delegate(&dave, 0), Some(bob));
+
+        // Dave already voted through eve, so bob only votes with his own contribution
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().nay, 400_000u64);
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().yay, 300_000u64);
+    });
+}
+
+#[test]
+fn redelegating_moves_the_delegator_to_the_new_delegate() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), None, eve));
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), None, eve));
+        assert_eq!(Proposals::delegators(&eve).into_inner(), vec![charlie.clone()]);
+
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), None, bob));
+        assert!(Proposals::delegators(&eve).is_empty());
+        assert_eq!(Proposals::delegators(&bob).into_inner(), vec![charlie]);
+    });
+}
+
+#[test]
+fn delegates_accept_a_bounded_number_of_delegators() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        assert_ok!(Proposals::delegate_votes(Origin::signed(bob), None, eve));
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), None, eve));
+        assert_noop!(
+            Proposals::delegate_votes(Origin::signed(dave), None, eve),
+            Error::<Test>::TooManyDelegators
+        );
+        // Delegations on a project are bounded separately
+        assert_ok!(Proposals::delegate_votes(Origin::signed(dave), Some(0), eve));
+    });
+}
+
+#[test]
+fn only_cast_milestone_votes_can_be_changed() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        submit_first_milestone(alice);
+        assert_noop!(
+            Proposals::change_milestone_vote(Origin::signed(dave), 0, 0, true),
+            Error::<Test>::VoteDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn milestone_votes_cannot_be_changed_to_the_same_vote() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_noop!(
+            Proposals::change_milestone_vote(Origin::signed(bob), 0, 0, false),
+            Error::<Test>::VoteUnchanged
+        );
+    });
+}
+
+#[test]
+fn changed_milestone_votes_move_the_tally() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+
+        assert_ok!(Proposals::change_milestone_vote(Origin::signed(bob), 0, 0, true));
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::VoteChanged(bob, 0, 0, Some(true), 4))
+        );
+        let vote = Proposals::milestone_votes((0, 0)).unwrap();
+        assert_eq!((vote.yay, vote.nay), (1_000_000u64, 0u64));
+    });
+}
+
+#[test]
+fn delegators_overriding_their_delegate_take_their_contribution_along() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        let _ = Currencies::deposit(CurrencyId::Native, &charlie, additional_amount);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 600_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(charlie), 0, 400_000u64));
+        assert_ok!(Proposals::delegate_votes(Origin::signed(charlie), Some(0), eve));
+        submit_first_milestone(alice);
+
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(eve), 0, 0, true));
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, true));
+        assert_ok!(Proposals::change_milestone_vote(Origin::signed(charlie), 0, 0, false));
+        let vote = Proposals::milestone_votes((0, 0)).unwrap();
+        assert_eq!((vote.yay, vote.nay), (600_000u64, 400_000u64));
+        let round_key = Proposals::milestone_voting_rounds((0, 0)).unwrap();
+        assert!(Proposals::delegated_votes((eve, 0, 0, round_key)).is_empty());
+
+        // The delegate no longer votes with the delegator's contribution
+        assert_ok!(Proposals::change_milestone_vote(Origin::signed(eve), 0, 0, false));
+        let vote = Proposals::milestone_votes((0, 0)).unwrap();
+        assert_eq!((vote.yay, vote.nay), (600_000u64, 400_000u64));
+    });
+}
+
+#[test]
+fn removed_milestone_votes_can_be_cast_again() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, true));
+
+        assert_ok!(Proposals::remove_milestone_vote(Origin::signed(bob), 0, 0));
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().yay, 0u64);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().nay, 1_000_000u64);
+    });
+}
+
+#[test]
+fn milestone_votes_cannot_be_changed_once_voting_ends() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(alice, milestones(&[100]), || {}, 1, &[(bob, 1_000_000u64)]);
+        reject_first_milestone(alice, bob);
+        assert_noop!(
+            Proposals::change_milestone_vote(Origin::signed(bob), 0, 0, true),
+            Error::<Test>::MilestoneVotingNotActive
+        );
+    });
+}
+
+#[test]
+fn milestone_submissions_snapshot_the_voting_weights() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        let round_key = Proposals::milestone_voting_rounds((0, 0)).unwrap();
+        assert_eq!(Proposals::milestone_vote_snapshot_totals(round_key), Some((1_000_000u64, 3)));
+        assert_eq!(Proposals::milestone_vote_snapshots(round_key, &charlie), Some(300_000u64));
+    });
+}
+
+#[test]
+fn milestone_votes_use_the_weights_snapshotted_at_submission() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+
+        // Contributions changing after the submission do not move the tally
+        Contributions::<Test>::insert(0, &charlie, 900_000u64);
+        ProjectContributionsTotal::<Test>::insert(0, 1_600_000u64);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(charlie), 0, 0, true));
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        let vote = Proposals::milestone_votes((0, 0)).unwrap();
+        assert_eq!((vote.yay, vote.nay), (300_000u64, 400_000u64));
+    });
+}
+
+#[test]
+fn contributors_after_the_submission_cannot_vote() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+
+        Contributions::<Test>::insert(0, &eve, 100_000u64);
+        assert_noop!(
+            Proposals::vote_on_milestone(Origin::signed(eve), 0, 0, true),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+    });
+}
+
+#[test]
+fn finalising_a_milestone_vote_clears_its_snapshot() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        let round_key = Proposals::milestone_voting_rounds((0, 0)).unwrap();
+        for voter in [&bob, &charlie, &dave] {
+            assert_ok!(Proposals::vote_on_milestone(Origin::signed(voter.clone()), 0, 0, true));
+        }
+
+        // Every snapshotted voter voted, so the vote can be finalised before the window ends
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+        assert_eq!(Proposals::milestone_vote_snapshot_totals(round_key), None);
+        assert_eq!(Proposals::milestone_vote_snapshots(round_key, &bob), None);
+    });
+}
+
+#[test]
+fn projects_accept_a_bounded_number_of_contributors() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+    let ferdie = get_account_id_from_seed::<sr25519::Public>("Ferdie");
+    let additional_amount = 10_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        for account in [&charlie, &dave, &eve, &ferdie] {
+            let _ = Currencies::deposit(CurrencyId::Native, account, additional_amount);
+        }
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 10,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        for contributor in [&alice, &bob, &charlie, &dave] {
+            assert_ok!(Proposals::contribute(Origin::signed(contributor.clone()), 0, 1_000u64));
+        }
+        assert_eq!(Proposals::project_contributor_count(0), 4);
+        assert_noop!(
+            Proposals::contribute(Origin::signed(eve), 0, 1_000u64),
+            Error::<Test>::TooManyContributors
+        );
+        // Existing contributors can still add to their contribution
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000u64));
+
+        // A withdrawn contribution frees a spot
+        assert_ok!(Proposals::withdraw_contribution(Origin::signed(dave), 0, 1_000u64));
+        assert_eq!(Proposals::project_contributor_count(0), 3);
+        assert_ok!(Proposals::contribute(Origin::signed(ferdie), 0, 1_000u64));
+    });
+}
+
+#[test]
+fn conviction_votes_require_a_contribution() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+    let eve = get_account_id_from_seed::<sr25519::Public>("Eve");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        assert_noop!(
+            Proposals::vote_on_milestone_with_conviction(
+                Origin::signed(eve),
+                0,
+                0,
+                true,
+                Conviction::Locked2x,
+                100_000u64
+            ),
+            Error::<Test>::OnlyContributorsCanVote
+        );
+    });
+}
+
+#[test]
+fn conviction_votes_require_locked_funds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        assert_noop!(
+            Proposals::vote_on_milestone_with_conviction(
+                Origin::signed(bob),
+                0,
+                0,
+                true,
+                Conviction::Locked2x,
+                0u64
+            ),
+            Error::<Test>::ConvictionRequiresContribution
+        );
+        assert_noop!(
+            Proposals::vote_on_milestone_with_conviction(
+                Origin::signed(bob),
+                0,
+                0,
+                true,
+                Conviction::Locked2x,
+                100_000_000u64
+            ),
+            Error::<Test>::InsufficientBalanceForConviction
+        );
+    });
+}
+
+#[test]
+fn conviction_votes_require_a_native_currency_project() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        Projects::<Test>::mutate(0, |project| {
+            if let Some(project) = project {
+                project.currency_id = CurrencyId::KSM;
+            }
+        });
+        assert_noop!(
+            Proposals::vote_on_milestone_with_conviction(
+                Origin::signed(bob),
+                0,
+                0,
+                true,
+                Conviction::Locked2x,
+                100_000u64
+            ),
+            Error::<Test>::ConvictionRequiresNativeCurrency
+        );
+    });
+}
+
+#[test]
+fn conviction_multiplies_the_part_of_the_vote_backed_by_locked_funds() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone_with_conviction(
+            Origin::signed(bob),
+            0,
+            0,
+            true,
+            Conviction::Locked3x,
+            100_000u64
+        ));
+        // 400_000 of own weight, of which 100_000 is tripled
+        assert_eq!(Proposals::milestone_votes((0, 0)).unwrap().yay, 600_000u64);
+        assert_eq!(Proposals::conviction_locks(&bob).into_inner(), vec![(24, 100_000u64)]);
+        assert_eq!(Balances::usable_balance(&bob), Balances::free_balance(&bob) - 100_000u64);
+    });
+}
+
+#[test]
+fn conviction_stays_with_a_changed_vote() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone_with_conviction(
+            Origin::signed(bob),
+            0,
+            0,
+            true,
+            Conviction::Locked3x,
+            100_000u64
+        ));
+        assert_ok!(Proposals::change_milestone_vote(Origin::signed(bob), 0, 0, false));
+        let vote = Proposals::milestone_votes((0, 0)).unwrap();
+        assert_eq!((vote.yay, vote.nay), (0u64, 600_000u64));
+    });
+}
+
+#[test]
+fn conviction_locks_are_released_when_their_period_ends() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        assert_ok!(Proposals::vote_on_milestone_with_conviction(
+            Origin::signed(bob),
+            0,
+            0,
+            true,
+            Conviction::Locked3x,
+            100_000u64
+        ));
+
+        run_to_block(23);
+        assert_eq!(Balances::locks(&bob).len(), 1);
+        run_to_block(24);
+        assert!(Balances::locks(&bob).is_empty());
+        assert!(Proposals::conviction_locks(&bob).is_empty());
+        assert_eq!(
+            <frame_system::Pallet<Test>>::events()
+                .pop()
+                .expect("Expected at least one EventRecord to be found")
+                .event,
+            mock::Event::from(proposals::Event::ConvictionLockReleased(bob, 0u64))
+        );
+    });
+}
+
+#[test]
+fn accounts_hold_a_bounded_number_of_conviction_locks() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+    let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+    ExtBuilder.build().execute_with(|| {
+        fund_project(
+            alice,
+            milestones(&[100]),
+            || {},
+            1,
+            &[(bob, 400_000u64), (charlie, 300_000u64), (dave, 300_000u64)],
+        );
+        submit_first_milestone(alice);
+        let locks: BoundedVec<(u64, u64), MaxConvictionLocks> = vec![(30, 1u64), (40, 1u64)]
+            .try_into()
+            .expect("input should be of decent length");
+        ConvictionLocks::<Test>::insert(&bob, locks);
+        assert_noop!(
+            Proposals::vote_on_milestone_with_conviction(
+                Origin::signed(bob),
+                0,
+                0,
+                true,
+                Conviction::Locked2x,
+                100_000u64
+            ),
+            Error::<Test>::TooManyConvictionLocks
+        );
+    });
+}
+
+#[test]
+fn migration_v2_indexes_rounds_by_project() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+        create_project(alice);
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        run_to_block(System::block_number() + 2);
+        for project_keys in [bounded_vec![0], bounded_vec![1]] {
+            assert_ok!(Proposals::schedule_round(
+                round_origin(),
+                System::block_number() + 1,
+                System::block_number() + 10,
+                project_keys,
+                RoundType::ContributionRound
+            ));
+        }
+        assert_ok!(Proposals::cancel_round(round_origin(), 2));
+        assert_eq!(Proposals::project_rounds(0, RoundType::ContributionRound), Some(1));
+        assert_eq!(Proposals::project_rounds(1, RoundType::ContributionRound), Some(0));
+
+        proposals::ProjectRounds::<Test>::remove(0, RoundType::ContributionRound);
+        proposals::ProjectRounds::<Test>::remove(1, RoundType::ContributionRound);
+        proposals::ProjectPreviousRounds::<Test>::remove(0, 1);
+        StorageVersion::new(1).put::<Proposals>();
+        migration::v2::migrate::<Test>();
+
+        assert_eq!(Proposals::project_rounds(0, RoundType::ContributionRound), Some(1));
+        assert_eq!(Proposals::project_previous_rounds(0, 1), Some(0));
+        // Cancelled rounds are not indexed
+        assert_eq!(Proposals::project_rounds(1, RoundType::ContributionRound), Some(0));
+        assert_eq!(Proposals::project_previous_rounds(1, 2), None);
+        assert_eq!(Proposals::project_rounds(0, RoundType::VotingRound), None);
+        assert_eq!(StorageVersion::get::<Proposals>(), 2);
+    });
+}
+
+#[test]
+fn query_helpers_return_project_state() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+    let contribution_amount = 1_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project(alice);
+        create_project(alice);
+
+        assert_eq!(Proposals::get_projects(0, 10).len(), 2);
+        assert_eq!(Proposals::get_projects(1, 10)[0].0, 1);
+        assert!(Proposals::get_active_round(0).is_none());
+
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0],
+            RoundType::ContributionRound
+        ));
+        assert_eq!(Proposals::get_active_round(0).map(|(round_key, _)| round_key), Some(0));
+        assert!(Proposals::get_active_round(1).is_none());
+
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, contribution_amount));
+        assert_eq!(Proposals::contributions(0, bob), Some(contribution_amount));
+        assert_eq!(Proposals::get_withdrawable_funds(0).unwrap(), 0);
+
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, Some(bounded_vec![0])));
+        assert_eq!(Proposals::get_withdrawable_funds(0).unwrap(), contribution_amount);
+
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        run_to_block(4);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, true));
+        assert_eq!(Proposals::get_user_milestone_votes(0, bob), vec![(0, 1, true)]);
+    });
+}
+
+#[test]
+fn user_milestone_votes_are_listed_for_every_submission() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    let additional_amount = 10_000_000u64;
+
+    ExtBuilder.build().execute_with(|| {
+        deposit_initial_balance(&alice, &bob, additional_amount);
+        create_project(alice);
+        create_project(alice);
+        assert_ok!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number() - 1,
+            System::block_number() + 1,
+            bounded_vec![0, 1],
+            RoundType::ContributionRound
+        ));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 0, 1_000_000u64));
+        assert_ok!(Proposals::contribute(Origin::signed(bob), 1, 1_000_000u64));
+        run_to_block(3);
+        assert_ok!(Proposals::approve(admin_origin(), 0, None));
+        assert_ok!(Proposals::approve(admin_origin(), 1, None));
+
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 1, 0, milestone_evidence()));
+        run_to_block(4);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 1, 0, true));
+        assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+
+        run_to_block(14);
+        assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+        run_to_block(15);
+        assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, true));
+
+        // The voting round of the other project is left out
+        assert_eq!(
+            Proposals::get_user_milestone_votes(0, bob),
+            vec![(0, 1, false), (0, 3, true)]
+        );
+        assert_eq!(Proposals::get_user_milestone_votes(1, bob), vec![(0, 2, true)]);
+    });
+}
+
+#[test]
+fn test_schedule_round_fails_gracefully_with_empty_vec() {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    ExtBuilder.build().execute_with(|| {
+        create_project(alice);
+
+        assert_noop!(Proposals::schedule_round(
+            round_origin(),
+            System::block_number(),
+            System::block_number() + 1,
+            // Empty keys is the test.
+            bounded_vec![],
+            RoundType::ContributionRound
+        ), Error::<Test>::LengthMustExceedZero);
+    });
+}
+
+//common helper methods
+fn admin_origin() -> Origin {
+    Origin::signed(AdminAccount::get())
+}
+
+fn round_origin() -> Origin {
+    Origin::signed(RoundAccount::get())
+}
+
+fn refund_origin() -> Origin {
+    Origin::signed(RefundAccount::get())
+}
+
+fn create_project(alice: AccountId) {
+    assert_ok!(Proposals::create_project(
+        Origin::signed(alice),
+        //project name
+        b"Farmer's Project Sudan"
+        .to_vec()
+        .try_into()
+        .expect("test bytes should be of decent length;"),
+        //project logo
+        b"Imbue Logo"
+        .to_vec()
+        .try_into()
+        .expect("input should be of decent length"),
+        //project description
+        
+        b"This project is aimed at providing decentralised funding for a farming project."
+        .to_vec()
+        .try_into()
+        .expect("test bytes should be of decent length;"),
+        
+            //website
+        b"https://farmers.network"
+        .to_vec()
+        .try_into()
+        .expect("test bytes should be of decent length;"),
+
+        //milestone
+        bounded_vec![ProposedMilestone {
+            name: bounded_vec![],
+            percentage_to_unlock: 100,
+            deadline: None
+        }],
+        //funds required
+        1000000u64,
+        CurrencyId::Native
+    ));
+}
+
+fn create_project_multiple_milestones(
+    alice: AccountId,
+    proposed_milestones: Vec<ProposedMilestone>,
+) {
+    assert_ok!(Proposals::create_project(
+        Origin::signed(alice),
+        //project name
+        b"Farmer's Project Sudan"
+        .to_vec()
+        .try_into()
+        .expect("input should be of decent length"),
+        //project logo
+        b"Imbue Logo"
+        .to_vec()
+        .try_into()
+        .expect("input should be of decent length"),
+        //project description
+        
+        b"This project is aimed at providing decentralised funding for a farming project."
+        .to_vec()
+        .try_into()
+        .expect("input should be of decent length"),
+        //website
+        
+        b"https://farmers.network"
+        .to_vec()
+        .try_into()
+        .expect("input should be of decent length"),
+        
+        //milestone
+        proposed_milestones.try_into().expect("proposed milestones are too long"),
+        //funds required
+        1000000u64,
+        CurrencyId::Native
+    ));
+}
+
+/// Create a project with `proposed_milestones` and schedule it in a round ending `round_length` blocks
+/// from now. Once `configure` has set the project up each of the `contributions` is made to it.
+/// Alice and every contributor are given 10_000_000 beforehand.
+fn fund_project(
+    alice: AccountId,
+    proposed_milestones: Vec<ProposedMilestone>,
+    configure: impl FnOnce(),
+    round_length: u64,
+    contributions: &[(AccountId, u64)],
+) {
+    let additional_amount = 10_000_000u64;
+    let _ = Currencies::deposit(CurrencyId::Native, &alice, additional_amount);
+    for (contributor, _) in contributions {
+        let _ = Currencies::deposit(CurrencyId::Native, contributor, additional_amount);
+    }
+    create_project_multiple_milestones(alice, proposed_milestones);
+    assert_ok!(Proposals::schedule_round(
+        round_origin(),
+        System::block_number() - 1,
+        System::block_number() + round_length,
+        bounded_vec![0],
+        RoundType::ContributionRound
+    ));
+    configure();
+    for (contributor, amount) in contributions {
+        assert_ok!(Proposals::contribute(Origin::signed(*contributor), 0, *amount));
+    }
+}
+
+/// Milestones without a deadline unlocking each of `percentages` in turn.
+fn milestones(percentages: &[u32]) -> Vec<ProposedMilestone> {
+    percentages
+        .iter()
+        .map(|percentage_to_unlock| ProposedMilestone {
+            name: bounded_vec![],
+            percentage_to_unlock: *percentage_to_unlock,
+            deadline: None,
+        })
+        .collect()
+}
+
+/// Two 50% milestones, the first of which is due at `deadline`.
+fn milestones_due_at(deadline: u32) -> Vec<ProposedMilestone> {
+    vec![
+        ProposedMilestone {
+            name: b"milestone 1".to_vec().try_into().expect("input should be of decent length"),
+            percentage_to_unlock: 50,
+            deadline: Some(deadline),
+        },
+        ProposedMilestone {
+            name: b"milestone 2".to_vec().try_into().expect("input should be of decent length"),
+            percentage_to_unlock: 50,
+            deadline: None,
+        },
+    ]
+}
+
+fn two_milestones() -> BoundedProposedMilestones {
+    bounded_vec![
+        ProposedMilestone { name: bounded_vec![], percentage_to_unlock: 40, deadline: None },
+        ProposedMilestone { name: bounded_vec![], percentage_to_unlock: 60, deadline: None },
+    ]
+}
+
+/// Submit the first milestone and have Bob vote it down, leaving the chain at block 4.
+fn reject_first_milestone(alice: AccountId, bob: AccountId) {
+    submit_first_milestone(alice);
+    assert_ok!(Proposals::vote_on_milestone(Origin::signed(bob), 0, 0, false));
+    assert_ok!(Proposals::finalise_milestone_voting(Origin::signed(alice), 0, 0));
+}
+
+/// Approve the project funded in the current round and open the vote on its first milestone.
+fn submit_first_milestone(alice: AccountId) {
+    run_to_block(3);
+    assert_ok!(Proposals::approve(admin_origin(), 0, None));
+    assert_ok!(Proposals::submit_milestone(Origin::signed(alice), 0, 0, milestone_evidence()));
+    run_to_block(4);
+}
+
+fn deposit_initial_balance(alice: &AccountId, bob: &AccountId, additional_amount: u64) {
+    let _ = Currencies::deposit(CurrencyId::Native, &alice, additional_amount);
+
+    let _ = Currencies::deposit(CurrencyId::Native, &bob, additional_amount);
+}
+
+fn milestone_evidence() -> BoundedEvidenceField {
+    b"ipfs://QmMilestoneEvidence"
+        .to_vec()
+        .try_into()
+        .expect("input should be of decent length")
 }
 
 fn run_to_block(n: u64) {
@@ -11,18 +11,40 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn fund() -> Weight;
     fn create_project() -> Weight;
-    fn submit_milestone() -> Weight;
+    fn update_project() -> Weight;
+    fn manage_project() -> Weight;
+    fn accept_ownership_transfer(t: u32) -> Weight;
+    fn submit_milestone(c: u32) -> Weight;
+    fn finalise_milestone_voting(c: u32) -> Weight;
     fn schedule_round(s: u32) -> Weight;
-    fn cancel_round() -> Weight;
+    fn cancel_round(s: u32) -> Weight;
+    fn update_round_projects(s: u32) -> Weight;
     fn cancel() -> Weight;
     fn set_withdrawal_expiration() -> Weight;
     fn set_max_proposal_count_per_round(s: u32) -> Weight;
     fn set_is_identity_required() -> Weight;
+    fn set_milestone_voting_quorum() -> Weight;
+    fn set_milestone_approval_threshold() -> Weight;
+    fn set_funding_soft_cap() -> Weight;
     fn contribute() -> Weight;
+    fn vote_on_milestone(d: u32) -> Weight;
+    fn change_milestone_vote(d: u32) -> Weight;
+    fn vote_on_milestone_with_conviction(d: u32) -> Weight;
     fn finalize_round() -> Weight;
-    fn approve() -> Weight;
+    fn approve(c: u32) -> Weight;
     fn withdraw() -> Weight;
-    fn refund() -> Weight;
+    fn refund(c: u32) -> Weight;
+    fn vote_of_no_confidence(c: u32) -> Weight;
+    fn vote_on_overdue_milestone(c: u32) -> Weight;
+    fn fund_matching_pool() -> Weight;
+    fn withdraw_contribution() -> Weight;
+    fn release_reserved_contributions(c: u32) -> Weight;
+    fn claim_vested_funds() -> Weight;
+    fn delegate_votes() -> Weight;
+    fn distribute_matching_pool(s: u32, c: u32) -> Weight;
+    fn reject_project() -> Weight;
+    fn refund_contribution() -> Weight;
+    fn reclaim_expired_funds(c: u32) -> Weight;
 }
 
 /// Weights for pallet_quadratic_funding using the Substrate node and recommended hardware.
@@ -35,13 +57,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     }
     fn create_project() -> Weight {
         (25_000_000_u64)
-            .saturating_add(T::DbWeight::get().reads(2_u64))
-            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
     }
-    fn submit_milestone() -> Weight {
+    fn update_project() -> Weight {
         (25_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn manage_project() -> Weight {
+        (30_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn accept_ownership_transfer(t: u32) -> Weight {
+        (42_000_000_u64)
+            // Standard Error: 2_000
+            .saturating_add((3_000_000_u64).saturating_mul(t as Weight))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((t as Weight)))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+            .saturating_add(T::DbWeight::get().writes((t as Weight)))
+    }
+    fn submit_milestone(c: u32) -> Weight {
+        (25_000_000_u64)
+            .saturating_add((3_000_000_u64).saturating_mul(c as Weight))
             .saturating_add(T::DbWeight::get().reads(2_u64))
-            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c as Weight)))
+    }
+    fn finalise_milestone_voting(c: u32) -> Weight {
+        (30_000_000_u64)
+            .saturating_add((2_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c as Weight)))
     }
     fn schedule_round(s: u32) -> Weight {
         (33_595_000_u64)
@@ -50,10 +101,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(4_u64))
             .saturating_add(T::DbWeight::get().writes(2_u64))
     }
-    fn cancel_round() -> Weight {
+    fn cancel_round(s: u32) -> Weight {
         (24_000_000_u64)
+            .saturating_add((1_500_000_u64).saturating_mul(s as Weight))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s as Weight)))
+    }
+    fn update_round_projects(s: u32) -> Weight {
+        (30_000_000_u64)
+            .saturating_add((71_000_u64).saturating_mul(s as Weight))
             .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s as Weight)))
             .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s as Weight)))
     }
     fn cancel() -> Weight {
         (20_000_000_u64)
@@ -69,30 +130,143 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn set_is_identity_required() -> Weight {
         (1_000_000_u64).saturating_add(T::DbWeight::get().writes(1_u64))
     }
+    fn set_milestone_voting_quorum() -> Weight {
+        (1_000_000_u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_milestone_approval_threshold() -> Weight {
+        (1_000_000_u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_funding_soft_cap() -> Weight {
+        (1_000_000_u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
     fn contribute() -> Weight {
         (55_000_000_u64)
-            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn vote_on_milestone(d: u32) -> Weight {
+        (32_000_000_u64)
+            .saturating_add((9_000_000_u64).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d as Weight)))
             .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d as Weight)))
+    }
+    fn change_milestone_vote(d: u32) -> Weight {
+        (36_000_000_u64)
+            .saturating_add((8_000_000_u64).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d as Weight)))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d as Weight)))
+    }
+    fn vote_on_milestone_with_conviction(d: u32) -> Weight {
+        (48_000_000_u64)
+            .saturating_add((9_000_000_u64).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d as Weight)))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d as Weight)))
     }
     fn finalize_round() -> Weight {
         (23_000_000_u64)
             .saturating_add(T::DbWeight::get().reads(1_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
-    fn approve() -> Weight {
+    fn approve(c: u32) -> Weight {
         (26_000_000_u64)
-            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add((25_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
             .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
     }
     fn withdraw() -> Weight {
         (66_000_000_u64)
             .saturating_add(T::DbWeight::get().reads(4_u64))
             .saturating_add(T::DbWeight::get().writes(2_u64))
     }
-    fn refund() -> Weight {
-        (66_000_000_u64)
+    fn refund(c: u32) -> Weight {
+        (45_000_000_u64)
+            .saturating_add((28_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
+    }
+    fn vote_of_no_confidence(c: u32) -> Weight {
+        (52_000_000_u64)
+            .saturating_add((28_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
+    }
+    fn vote_on_overdue_milestone(c: u32) -> Weight {
+        (55_000_000_u64)
+            .saturating_add((28_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
+    }
+    fn fund_matching_pool() -> Weight {
+        (45_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn withdraw_contribution() -> Weight {
+        (50_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn release_reserved_contributions(c: u32) -> Weight {
+        (20_000_000_u64)
+            .saturating_add((30_000_000_u64).saturating_mul(c as Weight))
             .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c as Weight)))
+    }
+    fn claim_vested_funds() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn delegate_votes() -> Weight {
+        (15_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn distribute_matching_pool(s: u32, c: u32) -> Weight {
+        (30_000_000_u64)
+            // Standard Error: 10_000
+            .saturating_add((25_000_000_u64).saturating_mul(s as Weight))
+            // Standard Error: 2_000
+            .saturating_add((2_000_000_u64).saturating_mul(s as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s as Weight)))
+            .saturating_add(T::DbWeight::get().reads((s as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s as Weight)))
+    }
+    fn reject_project() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    fn refund_contribution() -> Weight {
+        (45_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn reclaim_expired_funds(c: u32) -> Weight {
+        (40_000_000_u64)
+            .saturating_add((26_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
             .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c as Weight)))
     }
 }
 
@@ -105,13 +279,42 @@ impl WeightInfo for () {
     }
     fn create_project() -> Weight {
         (25_000_000_u64)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
-    fn submit_milestone() -> Weight {
+    fn update_project() -> Weight {
         (25_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn manage_project() -> Weight {
+        (30_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn accept_ownership_transfer(t: u32) -> Weight {
+        (42_000_000_u64)
+            // Standard Error: 2_000
+            .saturating_add((3_000_000_u64).saturating_mul(t as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads((t as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+            .saturating_add(RocksDbWeight::get().writes((t as Weight)))
+    }
+    fn submit_milestone(c: u32) -> Weight {
+        (25_000_000_u64)
+            .saturating_add((3_000_000_u64).saturating_mul(c as Weight))
             .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c as Weight)))
+    }
+    fn finalise_milestone_voting(c: u32) -> Weight {
+        (30_000_000_u64)
+            .saturating_add((2_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c as Weight)))
     }
     fn schedule_round(s: u32) -> Weight {
         (33_595_000_u64)
@@ -120,10 +323,20 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
-    fn cancel_round() -> Weight {
+    fn cancel_round(s: u32) -> Weight {
         (24_000_000_u64)
+            .saturating_add((1_500_000_u64).saturating_mul(s as Weight))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s as Weight)))
+    }
+    fn update_round_projects(s: u32) -> Weight {
+        (30_000_000_u64)
+            .saturating_add((71_000_u64).saturating_mul(s as Weight))
             .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s as Weight)))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s as Weight)))
     }
     fn cancel() -> Weight {
         (20_000_000_u64)
@@ -139,29 +352,142 @@ impl WeightInfo for () {
     fn set_is_identity_required() -> Weight {
         (1_000_000_u64).saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+    fn set_milestone_voting_quorum() -> Weight {
+        (1_000_000_u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_milestone_approval_threshold() -> Weight {
+        (1_000_000_u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_funding_soft_cap() -> Weight {
+        (1_000_000_u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
     fn contribute() -> Weight {
         (55_000_000_u64)
-            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn vote_on_milestone(d: u32) -> Weight {
+        (32_000_000_u64)
+            .saturating_add((9_000_000_u64).saturating_mul(d as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(d as Weight)))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d as Weight)))
+    }
+    fn change_milestone_vote(d: u32) -> Weight {
+        (36_000_000_u64)
+            .saturating_add((8_000_000_u64).saturating_mul(d as Weight))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d as Weight)))
+    }
+    fn vote_on_milestone_with_conviction(d: u32) -> Weight {
+        (48_000_000_u64)
+            .saturating_add((9_000_000_u64).saturating_mul(d as Weight))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(d as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d as Weight)))
     }
     fn finalize_round() -> Weight {
         (23_000_000_u64)
             .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
-    fn approve() -> Weight {
+    fn approve(c: u32) -> Weight {
         (26_000_000_u64)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add((25_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
     }
     fn withdraw() -> Weight {
         (66_000_000_u64)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
-    fn refund() -> Weight {
-        (66_000_000_u64)
+    fn refund(c: u32) -> Weight {
+        (45_000_000_u64)
+            .saturating_add((28_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
+    }
+    fn vote_of_no_confidence(c: u32) -> Weight {
+        (52_000_000_u64)
+            .saturating_add((28_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
+    }
+    fn vote_on_overdue_milestone(c: u32) -> Weight {
+        (55_000_000_u64)
+            .saturating_add((28_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c as Weight)))
+    }
+    fn fund_matching_pool() -> Weight {
+        (45_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn withdraw_contribution() -> Weight {
+        (50_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn release_reserved_contributions(c: u32) -> Weight {
+        (20_000_000_u64)
+            .saturating_add((30_000_000_u64).saturating_mul(c as Weight))
             .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c as Weight)))
+    }
+    fn claim_vested_funds() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn delegate_votes() -> Weight {
+        (15_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn distribute_matching_pool(s: u32, c: u32) -> Weight {
+        (30_000_000_u64)
+            // Standard Error: 10_000
+            .saturating_add((25_000_000_u64).saturating_mul(s as Weight))
+            // Standard Error: 2_000
+            .saturating_add((2_000_000_u64).saturating_mul(s as Weight).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s as Weight)))
+            .saturating_add(RocksDbWeight::get().reads((s as Weight).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(s as Weight)))
+    }
+    fn reject_project() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn refund_contribution() -> Weight {
+        (45_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn reclaim_expired_funds(c: u32) -> Weight {
+        (40_000_000_u64)
+            .saturating_add((26_000_000_u64).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c as Weight)))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c as Weight)))
     }
 }
@@ -94,10 +94,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("imbue"),
     impl_name: create_runtime_str!("imbue"),
     authoring_version: 1,
-    spec_version: 1029,
+    spec_version: 1030,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 0,
 };
 
@@ -618,6 +618,10 @@ type HalfOfCouncil = EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>
 /// A majority of the Unit body from Rococo over XCM is our required administration origin.
 pub type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
 pub type MoreThanHalfCouncil = EnsureRootOr<HalfOfCouncil>;
+type HalfOfTechnicalCommittee = EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>;
+/// Refunds can also be started by the technical committee, e.g. when a project turns out to be a scam.
+pub type RefundOrigin =
+    EnsureRootOr<frame_support::traits::EitherOfDiverse<HalfOfCouncil, HalfOfTechnicalCommittee>>;
 
 // pub type MoreThanHalfCouncil = EnsureOneOf<
 // 	EnsureRoot<AccountId>,
@@ -750,6 +754,22 @@ parameter_types! {
     pub const ProposalsPalletId: PalletId = PalletId(*b"imbgrant");
     pub const MaxProposalsPerRound: u32 = 256;
     pub const MaxWithdrawalExpiration: BlockNumber = 180 * DAYS;
+    pub const MaxMilestoneFinalisationsPerBlock: u32 = 50;
+    pub const ExpiredFundsDestination: proposals::ExpiredFundsDestination =
+        proposals::ExpiredFundsDestination::Contributors;
+    pub const ProjectDepositPerItem: Balance = deposit(1, 0);
+    pub const ProjectDepositPerByte: Balance = deposit(0, 1);
+    pub const NoConfidenceThreshold: Perbill = Perbill::from_percent(75);
+    pub const NoConfidenceVotingPeriod: BlockNumber = 7 * DAYS;
+    pub const MilestoneDeadlineExtension: BlockNumber = 14 * DAYS;
+    pub const OverdueMilestoneMajority: Perbill = Perbill::from_percent(50);
+    pub const MilestoneResubmissionCooldown: BlockNumber = 2 * DAYS;
+    pub const MaxMilestoneResubmissions: u32 = 3;
+    pub const ConvictionVoteLockingPeriod: BlockNumber = 2 * DAYS;
+    pub const MaxConvictionLocks: u32 = 16;
+    pub const MaxDelegatorsPerDelegate: u32 = 100;
+    pub const MaxContributorsPerProject: u32 = 1000;
+    pub const MaxTeamMembersPerProject: u32 = 20;
 }
 
 parameter_types! {
@@ -808,6 +828,26 @@ impl proposals::Config for Runtime {
     type MultiCurrency = Currencies;
     type MaxProposalsPerRound = MaxProposalsPerRound;
     type MaxWithdrawalExpiration = MaxWithdrawalExpiration;
+    type MaxMilestoneFinalisationsPerBlock = MaxMilestoneFinalisationsPerBlock;
+    type ExpiredFundsDestination = ExpiredFundsDestination;
+    type TreasuryAccount = TreasuryAccount;
+    type DepositPerItem = ProjectDepositPerItem;
+    type DepositPerByte = ProjectDepositPerByte;
+    type NoConfidenceThreshold = NoConfidenceThreshold;
+    type NoConfidenceVotingPeriod = NoConfidenceVotingPeriod;
+    type MilestoneDeadlineExtension = MilestoneDeadlineExtension;
+    type OverdueMilestoneMajority = OverdueMilestoneMajority;
+    type MilestoneResubmissionCooldown = MilestoneResubmissionCooldown;
+    type MaxMilestoneResubmissions = MaxMilestoneResubmissions;
+    type NativeCurrency = Balances;
+    type ConvictionVoteLockingPeriod = ConvictionVoteLockingPeriod;
+    type MaxConvictionLocks = MaxConvictionLocks;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
+    type MaxContributorsPerProject = MaxContributorsPerProject;
+    type MaxTeamMembersPerProject = MaxTeamMembersPerProject;
+    type AdminOrigin = AdminOrigin;
+    type RoundOrigin = MoreThanHalfCouncil;
+    type RefundOrigin = RefundOrigin;
     type WeightInfo = ();
 }
 
@@ -1035,6 +1075,40 @@ impl_runtime_apis! {
         }
     }
 
+    impl proposals_rpc_runtime_api::ProposalsApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn get_project(project_key: proposals::ProjectKey) -> Option<proposals::Project<AccountId, Balance, BlockNumber>> {
+            ImbueProposals::projects(project_key)
+        }
+
+        fn get_projects(start_key: proposals::ProjectKey, limit: u32) -> Vec<(proposals::ProjectKey, proposals::Project<AccountId, Balance, BlockNumber>)> {
+            ImbueProposals::get_projects(start_key, limit)
+        }
+
+        fn get_contribution(project_key: proposals::ProjectKey, who: AccountId) -> Option<Balance> {
+            ImbueProposals::contributions(project_key, who)
+        }
+
+        fn get_user_milestone_votes(project_key: proposals::ProjectKey, who: AccountId) -> Vec<(proposals::MilestoneKey, proposals::RoundKey, bool)> {
+            ImbueProposals::get_user_milestone_votes(project_key, who)
+        }
+
+        fn get_withdrawable_funds(project_key: proposals::ProjectKey) -> Option<Balance> {
+            ImbueProposals::get_withdrawable_funds(project_key).ok()
+        }
+
+        fn get_milestone_submissions(project_key: proposals::ProjectKey, milestone_key: proposals::MilestoneKey) -> Vec<proposals::MilestoneSubmission<Balance, BlockNumber>> {
+            ImbueProposals::milestone_submissions((project_key, milestone_key))
+        }
+
+        fn get_vesting_schedules(project_key: proposals::ProjectKey) -> Vec<proposals::VestingSchedule<Balance, BlockNumber>> {
+            ImbueProposals::vesting_schedules(project_key)
+        }
+
+        fn get_active_round(project_key: proposals::ProjectKey) -> Option<(proposals::RoundKey, proposals::Round<BlockNumber>)> {
+            ImbueProposals::get_active_round(project_key)
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)